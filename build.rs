@@ -4,6 +4,8 @@ fn main() {
     let cfg = autocfg::new();
     cfg.emit_path_cfg("core::task::Poll", "has_task_poll");
     cfg.emit_path_cfg("std::task::Poll", "has_task_poll");
+    cfg.emit_path_cfg("core::time::Duration", "has_core_duration");
+    cfg.emit_path_cfg("std::time::Duration", "has_core_duration");
 
     // Needed to enable `#![no_std]` only on rustc versions that support it (rustc 1.6.0 and up).
     cfg.emit_rustc_version(1, 6);
@@ -11,9 +13,31 @@ fn main() {
     // Needed to enable custom error message propagation to `assert_eq`.
     cfg.emit_rustc_version(1, 11);
 
+    // Needed for `assert_ne!` in the `assert_*_ne!` macros.
+    cfg.emit_rustc_version(1, 13);
+
     // Needed for `assert_matches!`' minimum rust version.
     cfg.emit_rustc_version(1, 26);
 
+    // Needed for `$crate::` paths to the helper macros, which the newer macros expand to.
+    cfg.emit_rustc_version(1, 30);
+
+    // Needed for references to generic types in structs without explicit `T: 'a` bounds,
+    // e.g. in the helpers reporting the difference between the operands of `assert_lt!`.
+    cfg.emit_rustc_version(1, 31);
+
+    // Needed for `RangeBounds::contains` in `assert_in_range!` and `assert_not_in_range!`.
+    cfg.emit_rustc_version(1, 35);
+
+    // Needed for `$(...)?` repetitions in `assert_err_source_chain!` and `assert_err_source_chain_exact!`.
+    cfg.emit_rustc_version(1, 37);
+
+    // Needed for `core::any::type_name`, naming the types that do not implement `Debug`.
+    cfg.emit_rustc_version(1, 38);
+
+    // Needed for `let`-`else` statements in `assert_let!`.
+    cfg.emit_rustc_version(1, 65);
+
     if cfg.probe_rustc_version(1, 15) && !cfg.probe_rustc_version(1, 16) {
         autocfg::emit("has_private_in_public_issue");
     }
@@ -0,0 +1,301 @@
+use core::fmt;
+
+/// Tolerance used by [`assert_approx_eq!`] to decide whether two values are close enough.
+///
+/// The default tolerance is `Ulps(4)`. Absolute and relative tolerances must not be negative.
+///
+/// [`assert_approx_eq!`]: ./macro.assert_approx_eq.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// Values are equal if their absolute difference is not greater than the given value.
+    Absolute(f64),
+    /// Values are equal if their absolute difference is not greater than
+    /// the given fraction of the larger magnitude of the two.
+    Relative(f64),
+    /// Values are equal if they are at most the given number of
+    /// [units in the last place](https://en.wikipedia.org/wiki/Unit_in_the_last_place) apart.
+    ///
+    /// For [`Duration`], a unit in the last place is one nanosecond.
+    ///
+    /// [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
+    Ulps(u64),
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance::Ulps(4)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ApproxErrorKind {
+    NotANumber,
+    Difference(f64),
+    Ulps(u64),
+    LengthMismatch(usize, usize),
+}
+
+/// Reason why two values are not approximately equal.
+///
+/// Returned by [`Approx::approx_eq`] and printed by [`assert_approx_eq!`] on failure.
+///
+/// [`Approx::approx_eq`]: ./trait.Approx.html#tymethod.approx_eq
+/// [`assert_approx_eq!`]: ./macro.assert_approx_eq.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApproxError {
+    index: Option<usize>,
+    kind: ApproxErrorKind,
+}
+
+impl ApproxError {
+    fn new(kind: ApproxErrorKind) -> Self {
+        ApproxError { index: None, kind }
+    }
+
+    /// Marks the error as originating from the element at `index`.
+    ///
+    /// Useful when implementing [`Approx`] for collections by delegating to the elements.
+    ///
+    /// [`Approx`]: ./trait.Approx.html
+    pub fn at_index(self, index: usize) -> Self {
+        ApproxError {
+            index: Some(index),
+            kind: self.kind,
+        }
+    }
+
+    /// Returns the index of the first element that is not approximately equal, if any.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+impl fmt::Display for ApproxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(index) = self.index {
+            tri!(write!(f, "at index `{}`: ", index));
+        }
+        match self.kind {
+            ApproxErrorKind::NotANumber => f.write_str("`NaN` is never approximately equal"),
+            ApproxErrorKind::Difference(difference) => {
+                write!(f, "difference `{:?}` exceeds tolerance", difference)
+            }
+            ApproxErrorKind::Ulps(ulps) => {
+                write!(f, "distance of `{}` ULPs exceeds tolerance", ulps)
+            }
+            ApproxErrorKind::LengthMismatch(left, right) => write!(
+                f,
+                "lengths differ, left has `{}` elements, right has `{}`",
+                left, right
+            ),
+        }
+    }
+}
+
+/// Approximate equality, used by [`assert_approx_eq!`].
+///
+/// Implemented for `f32`, `f64`, [`Duration`], and element-wise for
+/// slices, arrays (up to 32 elements) and tuples (up to 12 elements) of such values.
+///
+/// [`assert_approx_eq!`]: ./macro.assert_approx_eq.html
+/// [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
+pub trait Approx {
+    /// Checks whether `self` and `other` are equal within the given `tolerance`.
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> Result<(), ApproxError>;
+}
+
+fn abs(value: f64) -> f64 {
+    if value < 0.0 {
+        -value
+    } else {
+        value
+    }
+}
+
+// The distance in ULPs is only computed with `ulps` when it is the tolerance being checked.
+fn check<F>(left: f64, right: f64, ulps: F, tolerance: Tolerance) -> Result<(), ApproxError>
+where
+    F: FnOnce() -> u64,
+{
+    match tolerance {
+        Tolerance::Absolute(limit) | Tolerance::Relative(limit) if limit < 0.0 => {
+            panic!("tolerance must not be negative, got `{:?}`", tolerance)
+        }
+        _ => {}
+    }
+    if left.is_nan() || right.is_nan() {
+        return Err(ApproxError::new(ApproxErrorKind::NotANumber));
+    }
+    if left == right {
+        return Ok(());
+    }
+    let difference = abs(left - right);
+    let within = match tolerance {
+        Tolerance::Absolute(epsilon) => difference <= epsilon,
+        Tolerance::Relative(fraction) => difference <= fraction * abs(left).max(abs(right)),
+        Tolerance::Ulps(max_ulps) => {
+            let ulps = ulps();
+            return if ulps <= max_ulps {
+                Ok(())
+            } else {
+                Err(ApproxError::new(ApproxErrorKind::Ulps(ulps)))
+            };
+        }
+    };
+    if within {
+        Ok(())
+    } else {
+        Err(ApproxError::new(ApproxErrorKind::Difference(difference)))
+    }
+}
+
+// Maps the bit representations onto a monotonic integer scale, so that the distance between
+// two floats is the number of representable values between them. Both zeroes map onto `0`.
+// Negative values are mirrored around zero by negating their magnitude.
+fn ulps_f64(left: f64, right: f64) -> u64 {
+    fn ordered(value: f64) -> i64 {
+        let bits = value.to_bits() as i64;
+        if bits < 0 {
+            -(bits & 0x7fff_ffff_ffff_ffff)
+        } else {
+            bits
+        }
+    }
+
+    let (left, right) = (ordered(left), ordered(right));
+    if left > right {
+        (left as u64).wrapping_sub(right as u64)
+    } else {
+        (right as u64).wrapping_sub(left as u64)
+    }
+}
+
+fn ulps_f32(left: f32, right: f32) -> u64 {
+    fn ordered(value: f32) -> i32 {
+        let bits = value.to_bits() as i32;
+        if bits < 0 {
+            -(bits & 0x7fff_ffff)
+        } else {
+            bits
+        }
+    }
+
+    let (left, right) = (ordered(left), ordered(right));
+    if left > right {
+        u64::from((left as u32).wrapping_sub(right as u32))
+    } else {
+        u64::from((right as u32).wrapping_sub(left as u32))
+    }
+}
+
+impl Approx for f64 {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> Result<(), ApproxError> {
+        check(*self, *other, || ulps_f64(*self, *other), tolerance)
+    }
+}
+
+impl Approx for f32 {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> Result<(), ApproxError> {
+        check(
+            f64::from(*self),
+            f64::from(*other),
+            || ulps_f32(*self, *other),
+            tolerance,
+        )
+    }
+}
+
+/// Absolute and relative tolerances are measured in seconds, ULPs in nanoseconds.
+#[cfg(has_core_duration)]
+impl Approx for core::time::Duration {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> Result<(), ApproxError> {
+        fn seconds(duration: core::time::Duration) -> f64 {
+            duration.as_secs() as f64 + f64::from(duration.subsec_nanos()) / 1_000_000_000.0
+        }
+
+        let nanos = || {
+            let difference = if self > other {
+                *self - *other
+            } else {
+                *other - *self
+            };
+            difference
+                .as_secs()
+                .saturating_mul(1_000_000_000)
+                .saturating_add(u64::from(difference.subsec_nanos()))
+        };
+        check(seconds(*self), seconds(*other), nanos, tolerance)
+    }
+}
+
+impl<'a, T: Approx + ?Sized> Approx for &'a T {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> Result<(), ApproxError> {
+        T::approx_eq(*self, *other, tolerance)
+    }
+}
+
+impl<T: Approx> Approx for [T] {
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> Result<(), ApproxError> {
+        if self.len() != other.len() {
+            return Err(ApproxError::new(ApproxErrorKind::LengthMismatch(
+                self.len(),
+                other.len(),
+            )));
+        }
+        for (index, (left, right)) in self.iter().zip(other).enumerate() {
+            tri!(left
+                .approx_eq(right, tolerance)
+                .map_err(|error| error.at_index(index)));
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_approx_for_arrays {
+    ($($len:expr)*) => {
+        $(
+            impl<T: Approx> Approx for [T; $len] {
+                fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> Result<(), ApproxError> {
+                    self[..].approx_eq(&other[..], tolerance)
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_for_arrays! {
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+}
+
+macro_rules! impl_approx_for_tuples {
+    ($(($($name:ident $index:tt)+))*) => {
+        $(
+            impl<$($name: Approx),+> Approx for ($($name,)+) {
+                fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> Result<(), ApproxError> {
+                    $(
+                        tri!(self.$index
+                            .approx_eq(&other.$index, tolerance)
+                            .map_err(|error| error.at_index($index)));
+                    )+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_for_tuples! {
+    (A 0)
+    (A 0 B 1)
+    (A 0 B 1 C 2)
+    (A 0 B 1 C 2 D 3)
+    (A 0 B 1 C 2 D 3 E 4)
+    (A 0 B 1 C 2 D 3 E 4 F 5)
+    (A 0 B 1 C 2 D 3 E 4 F 5 G 6)
+    (A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7)
+    (A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8)
+    (A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9)
+    (A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10)
+    (A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10 L 11)
+}
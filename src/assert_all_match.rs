@@ -0,0 +1,175 @@
+/// Asserts that every element of an iterator matches any of the given variants.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// The expression can be anything implementing [`IntoIterator`], and the elements are matched
+/// one by one, like with [`assert_matches!`], stopping at the first one that does not match.
+/// An empty iterator always passes.
+///
+/// On panic, this macro will print the index and the value of the first element
+/// that does not match.
+/// With a guard, the variant that matched is reported if only the guard does not hold.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_all_match!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let codes = [200, 201, 204];
+/// assert_all_match!(codes.iter(), 200..=299);
+///
+/// let results: [Result<i32, ()>; 2] = [Ok(1), Ok(2)];
+/// assert_all_match!(results.iter(), Ok(n) if *n > 0);
+///
+/// // With custom messages
+/// assert_all_match!(codes.iter(), 200..=299, "expecting {}", "success");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let options = [Some(1), None, Some(3)];
+/// assert_all_match!(options.iter(), Some(_));  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_all_match!`]: ./macro.debug_assert_all_match.html
+#[macro_export]
+macro_rules! assert_all_match {
+    (@all $iterable:expr, [$( $pattern:pat )|+], [$($arg:tt)*]) => {
+        for (index, element) in IntoIterator::into_iter($iterable).enumerate() {
+            match element {
+                $( $pattern )|+ => {},
+                element => {
+                    $crate::assert_all_match!(@panic [r#"assertion failed, element of iterator does not match any of the given variants.
+    index: {}
+    element: {:?}
+    variants: {}"#, index, $crate::__debug_value!(element), stringify!($($pattern) |+)] [$($arg)*]);
+                }
+            }
+        }
+    };
+    (@all $iterable:expr, [$( $pattern:pat )|+ if $guard:expr], [$($arg:tt)*]) => {
+        for (index, element) in IntoIterator::into_iter($iterable).enumerate() {
+            $crate::assert_matches!(@guarded element, [$($pattern)|+ if $guard], [{}], |element, variant| {
+                if let Some(variant) = variant {
+                    $crate::assert_all_match!(@panic [r#"assertion failed, element of iterator matches one of the given variants, but the guard does not hold.
+    index: {}
+    element: {:?}
+    variant: {}
+    guard: {}"#, index, $crate::__debug_value!(element), variant, stringify!($guard)] [$($arg)*]);
+                }
+                $crate::assert_all_match!(@panic [r#"assertion failed, element of iterator does not match any of the given variants.
+    index: {}
+    element: {:?}
+    variants: {}"#, index, $crate::__debug_value!(element), stringify!($($pattern) |+ if $guard)] [$($arg)*]);
+            })
+        }
+    };
+    (@panic [$format:expr, $($value:expr),+] []) => {
+        panic!($format, $($value),+)
+    };
+    (@panic [$format:expr, $($value:expr),+] [$($arg:tt)+]) => {
+        panic!(concat!($format, ": {}"), $($value),+, format_args!($($arg)+))
+    };
+
+    ($iterable:expr, $( $pattern:pat )|+) => {
+        $crate::assert_all_match!(@all $iterable, [$($pattern)|+], [])
+    };
+    ($iterable:expr, $( $pattern:pat )|+ if $guard:expr) => {
+        $crate::assert_all_match!(@all $iterable, [$($pattern)|+ if $guard], [])
+    };
+    ($iterable:expr, $( $pattern:pat )|+, $($arg:tt)+) => {
+        $crate::assert_all_match!(@all $iterable, [$($pattern)|+], [$($arg)+])
+    };
+    ($iterable:expr, $( $pattern:pat )|+ if $guard:expr, $($arg:tt)+) => {
+        $crate::assert_all_match!(@all $iterable, [$($pattern)|+ if $guard], [$($arg)+])
+    };
+}
+
+/// Asserts that every element of an iterator matches any of the given variants in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_all_match!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_all_match!`]: ./macro.assert_all_match.html
+#[macro_export]
+macro_rules! debug_assert_all_match {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_all_match!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    enum Event {
+        Login(&'static str),
+        Logout(&'static str),
+    }
+
+    #[test]
+    fn all_match() {
+        assert_all_match!(core::iter::empty::<Event>(), Event::Login(_));
+        assert_all_match!(
+            &[Event::Login("alice"), Event::Logout("bob")],
+            Event::Login(user) | Event::Logout(user) if user.len() > 2
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, element of iterator does not match any of the given variants.
+    index: 1
+    element: Logout("bob")
+    variants: Event::Login(_)"#
+    )]
+    fn default_panic_message() {
+        assert_all_match!(
+            &[
+                Event::Login("alice"),
+                Event::Logout("bob"),
+                Event::Logout("eve")
+            ],
+            Event::Login(_)
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, element of iterator matches one of the given variants, but the guard does not hold.
+    index: 1
+    element: Logout("bob")
+    variant: Event::Logout(user)
+    guard: user.len() > 3: checking bob"#
+    )]
+    fn guard_panic_message() {
+        assert_all_match!(
+            &[Event::Login("alice"), Event::Logout("bob")],
+            Event::Login(user) | Event::Logout(user) if user.len() > 3,
+            "checking {}",
+            "bob"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+/// Asserts that at least one element of an iterator matches any of the given variants.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// The expression can be anything implementing [`IntoIterator`], and the elements are matched
+/// one by one, like with [`assert_matches!`], stopping at the first one that matches.
+/// An empty iterator always fails.
+///
+/// On panic, this macro will print all the elements, so the iterator must implement [`Clone`]
+/// for the elements to be listed again once they have all been checked. Iterators over
+/// references to the elements, such as the ones returned by `iter()`, are usually the
+/// cheapest to clone.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_any_match!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let codes = [404, 200, 500];
+/// assert_any_match!(codes.iter(), 200..=299);
+///
+/// let results: [Result<i32, ()>; 2] = [Err(()), Ok(2)];
+/// assert_any_match!(results.iter(), Ok(n) if *n > 0);
+///
+/// // With custom messages
+/// assert_any_match!(codes.iter(), 200..=299, "expecting {}", "success");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let options = [None, None];
+/// assert_any_match!(options.iter(), Some(1));  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`Clone`]: https://doc.rust-lang.org/core/clone/trait.Clone.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_any_match!`]: ./macro.debug_assert_any_match.html
+#[macro_export]
+macro_rules! assert_any_match {
+    (@any $iterable:expr, [$($variant:tt)+], [$($arg:tt)*]) => {
+        match IntoIterator::into_iter($iterable) {
+            elements => {
+                let mut matched = false;
+                for element in elements.clone() {
+                    if $crate::assert_any_match!(@matches element, $($variant)+) {
+                        matched = true;
+                        break;
+                    }
+                }
+                if !matched {
+                    $crate::assert_any_match!(@panic [r#"assertion failed, no element of iterator matches any of the given variants.
+    elements: {:?}
+    variants: {}"#, $crate::__private::Elements::new(elements, |element, f| {
+        $crate::__private::Debug::fmt(&$crate::__debug_value!(*element), f)
+    }), stringify!($($variant)+)] [$($arg)*]);
+                }
+            }
+        }
+    };
+    (@matches $element:ident, $( $pattern:pat )|+) => {
+        match $element {
+            $( $pattern )|+ => true,
+            _ => false,
+        }
+    };
+    (@matches $element:ident, $( $pattern:pat )|+ if $guard:expr) => {
+        match $element {
+            $( $pattern )|+ if $guard => true,
+            _ => false,
+        }
+    };
+    (@panic [$format:expr, $($value:expr),+] []) => {
+        panic!($format, $($value),+)
+    };
+    (@panic [$format:expr, $($value:expr),+] [$($arg:tt)+]) => {
+        panic!(concat!($format, ": {}"), $($value),+, format_args!($($arg)+))
+    };
+
+    ($iterable:expr, $( $pattern:pat )|+) => {
+        $crate::assert_any_match!(@any $iterable, [$($pattern)|+], [])
+    };
+    ($iterable:expr, $( $pattern:pat )|+ if $guard:expr) => {
+        $crate::assert_any_match!(@any $iterable, [$($pattern)|+ if $guard], [])
+    };
+    ($iterable:expr, $( $pattern:pat )|+, $($arg:tt)+) => {
+        $crate::assert_any_match!(@any $iterable, [$($pattern)|+], [$($arg)+])
+    };
+    ($iterable:expr, $( $pattern:pat )|+ if $guard:expr, $($arg:tt)+) => {
+        $crate::assert_any_match!(@any $iterable, [$($pattern)|+ if $guard], [$($arg)+])
+    };
+}
+
+/// Asserts that at least one element of an iterator matches any of the given variants in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_any_match!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_any_match!`]: ./macro.assert_any_match.html
+#[macro_export]
+macro_rules! debug_assert_any_match {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_any_match!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    enum Event {
+        Login(&'static str),
+        Logout(&'static str),
+    }
+
+    const EVENTS: [Event; 3] = [
+        Event::Login("alice"),
+        Event::Login("bob"),
+        Event::Logout("alice"),
+    ];
+
+    #[test]
+    fn any_match() {
+        assert_any_match!(EVENTS.iter(), Event::Logout(_));
+        assert_any_match!(EVENTS.iter(), Event::Login(user) | Event::Logout(user) if *user == "bob");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, no element of iterator matches any of the given variants.
+    elements: []
+    variants: Event::Login(_)"#
+    )]
+    fn empty_iterator() {
+        assert_any_match!(core::iter::empty::<Event>(), Event::Login(_));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, no element of iterator matches any of the given variants.
+    elements: [Login("alice"), Login("bob"), Logout("alice")]
+    variants: Event::Login(user) | Event::Logout(user) if user.len() > 5: checking alice"#
+    )]
+    fn custom_panic_message() {
+        assert_any_match!(
+            EVENTS.iter(),
+            Event::Login(user) | Event::Logout(user) if user.len() > 5,
+            "checking {}",
+            "alice"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+/// Asserts that two expressions are approximately equal to each other.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Requires that both expressions implement the [`Approx`] trait, which is provided
+/// for `f32`, `f64`, [`Duration`], and element-wise for slices, arrays and tuples of those.
+///
+/// By default, values are allowed to be 4 [ULPs] apart. A different [`Tolerance`]
+/// can be chosen by passing one of `abs = <f64>`, `rel = <f64>` or `ulps = <u64>`
+/// as the third argument. `NaN` is never approximately equal to anything, including itself.
+///
+/// When comparing collections, the failure message reports the index of the first element
+/// that is out of tolerance.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_approx_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_approx_eq!(0.1 + 0.2, 0.3);
+/// assert_approx_eq!(1.0f32, 1.001, abs = 0.01);
+/// assert_approx_eq!(100.0, 101.0, rel = 0.05);
+/// assert_approx_eq!([1.0, 2.0], [1.0, 2.0 + 1e-12], abs = 1e-9);
+///
+/// // With custom messages
+/// assert_approx_eq!(1.0, 1.001, abs = 0.01, "Expecting {} to be close to {}", 1.0, 1.001);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_approx_eq!((1.0, 2.0), (1.0, 2.5), abs = 0.1);  // Will panic
+/// # }
+/// ```
+///
+/// [`Approx`]: ./trait.Approx.html
+/// [`Tolerance`]: ./enum.Tolerance.html
+/// [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
+/// [ULPs]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_approx_eq!`]: ./macro.debug_assert_approx_eq.html
+#[macro_export]
+macro_rules! assert_approx_eq {
+    (@check $left:expr, $right:expr, $tolerance:expr) => {
+        match (&$left, &$right, $tolerance) {
+            (left_val, right_val, tolerance) => {
+                if let Err(error) = $crate::Approx::approx_eq(left_val, right_val, tolerance) {
+                    panic!(r#"assertion failed: `(left ~= right)`
+    left: `{:?}`,
+    right: `{:?}`,
+    tolerance: `{:?}`,
+    reason: {}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), tolerance, error)
+                }
+            }
+        }
+    };
+    (@check $left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        match (&$left, &$right, $tolerance) {
+            (left_val, right_val, tolerance) => {
+                if let Err(error) = $crate::Approx::approx_eq(left_val, right_val, tolerance) {
+                    panic!(r#"assertion failed: `(left ~= right)`
+    left: `{:?}`,
+    right: `{:?}`,
+    tolerance: `{:?}`,
+    reason: {}: {}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), tolerance, error, format_args!($($arg)+))
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr) => {
+        $crate::assert_approx_eq!(@check $left, $right, <$crate::Tolerance as Default>::default());
+    };
+    ($left:expr, $right:expr,) => {
+        $crate::assert_approx_eq!($left, $right);
+    };
+    ($left:expr, $right:expr, abs = $epsilon:expr) => {
+        $crate::assert_approx_eq!(@check $left, $right, $crate::Tolerance::Absolute($epsilon));
+    };
+    ($left:expr, $right:expr, abs = $epsilon:expr,) => {
+        $crate::assert_approx_eq!($left, $right, abs = $epsilon);
+    };
+    ($left:expr, $right:expr, abs = $epsilon:expr, $($arg:tt)+) => {
+        $crate::assert_approx_eq!(@check $left, $right, $crate::Tolerance::Absolute($epsilon), $($arg)+);
+    };
+    ($left:expr, $right:expr, rel = $fraction:expr) => {
+        $crate::assert_approx_eq!(@check $left, $right, $crate::Tolerance::Relative($fraction));
+    };
+    ($left:expr, $right:expr, rel = $fraction:expr,) => {
+        $crate::assert_approx_eq!($left, $right, rel = $fraction);
+    };
+    ($left:expr, $right:expr, rel = $fraction:expr, $($arg:tt)+) => {
+        $crate::assert_approx_eq!(@check $left, $right, $crate::Tolerance::Relative($fraction), $($arg)+);
+    };
+    ($left:expr, $right:expr, ulps = $ulps:expr) => {
+        $crate::assert_approx_eq!(@check $left, $right, $crate::Tolerance::Ulps($ulps));
+    };
+    ($left:expr, $right:expr, ulps = $ulps:expr,) => {
+        $crate::assert_approx_eq!($left, $right, ulps = $ulps);
+    };
+    ($left:expr, $right:expr, ulps = $ulps:expr, $($arg:tt)+) => {
+        $crate::assert_approx_eq!(@check $left, $right, $crate::Tolerance::Ulps($ulps), $($arg)+);
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::assert_approx_eq!(@check $left, $right, <$crate::Tolerance as Default>::default(), $($arg)+);
+    };
+}
+
+/// Asserts that two expressions are approximately equal to each other in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_approx_eq!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_approx_eq!`]: ./macro.assert_approx_eq.html
+#[macro_export]
+macro_rules! debug_assert_approx_eq {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_approx_eq!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn default_tolerance() {
+        assert_approx_eq!(0.1 + 0.2, 0.3);
+        assert_approx_eq!(0.1f32 + 0.2, 0.3);
+        assert_approx_eq!(-0.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left ~= right)`
+    left: `1.0`,
+    right: `1.5`,
+    tolerance: `Absolute(0.1)`,
+    reason: difference `0.5` exceeds tolerance"#)]
+    fn default_panic_message() {
+        assert_approx_eq!(1.0, 1.5, abs = 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "reason: distance of `1` ULPs exceeds tolerance: checking 1.0")]
+    fn custom_panic_message() {
+        assert_approx_eq!(1.0f32, 1.0000001, ulps = 0, "checking {:?}", 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "reason: `NaN` is never approximately equal")]
+    fn nan() {
+        let nan = "NaN".parse::<f64>().unwrap();
+        assert_approx_eq!(nan, nan, abs = "inf".parse::<f64>().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "tolerance must not be negative, got `Relative(-0.1)`")]
+    fn negative_tolerance() {
+        assert_approx_eq!(1.0, 1.0, rel = -0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "reason: at index `2`: difference `0.5` exceeds tolerance")]
+    fn first_offending_index() {
+        assert_approx_eq!(
+            &[1.0, 2.0, 3.0, 4.0][..],
+            &[1.0, 2.0, 3.5, 5.0][..],
+            rel = 0.01
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "reason: lengths differ, left has `2` elements, right has `3`")]
+    fn length_mismatch() {
+        assert_approx_eq!(&[1.0, 2.0][..], &[1.0, 2.0, 3.0][..]);
+    }
+
+    #[test]
+    #[cfg(has_core_duration)]
+    fn duration() {
+        use core::time::Duration;
+
+        assert_approx_eq!(
+            Duration::from_millis(100),
+            Duration::from_millis(101),
+            abs = 0.002
+        );
+        assert_approx_eq!(Duration::new(1, 0), Duration::new(1, 3), ulps = 3);
+    }
+}
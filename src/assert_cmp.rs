@@ -0,0 +1,303 @@
+/// Asserts that a chain of comparisons holds.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Accepts any number of operands separated by the `<`, `<=`, `>`, `>=`, `==` and `!=`
+/// operators, for example `assert_cmp!(min <= x < max)`, which is equivalent to
+/// `min <= x && x < max`. Every operand is evaluated exactly once, before any comparison is made.
+///
+/// Requires that neighbouring operands be comparable with the operator between them.
+/// On failure, the first link of the chain that does not hold is reported
+/// along with the values of all operands.
+///
+/// Operands are split on the top-level comparison operators, so operands that contain
+/// those operators themselves (e.g. turbofish generics or nested comparisons)
+/// need to be wrapped in parentheses. The same goes for operands containing the `&&`
+/// and `||` operators, which are rejected at compile time otherwise, as
+/// `assert_cmp!(x == a && b)` would not mean `(x == a) && b`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_cmp!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let (min, x, max) = (1, 3, 5);
+///
+/// assert_cmp!(x > 2);
+/// assert_cmp!(min <= x < max);
+/// assert_cmp!(min < x != 4 <= max);
+///
+/// // With custom messages
+/// assert_cmp!(min <= x < max, "Expecting {} to be in bounds", x);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let (min, x, max) = (1, 5, 5);
+///
+/// assert_cmp!(min <= x < max);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_cmp!`]: ./macro.debug_assert_cmp.html
+#[macro_export]
+macro_rules! assert_cmp {
+    // Splits the input into operands and operators, up to the optional custom message.
+    (@parse $src:tt [$($chain:tt)*] [$($operand:tt)+] < $($rest:tt)+) => {
+        $crate::assert_cmp!(@push $src < [$($chain)* ($($operand)+) <] $($rest)+)
+    };
+    (@parse $src:tt [$($chain:tt)*] [$($operand:tt)+] <= $($rest:tt)+) => {
+        $crate::assert_cmp!(@push $src <= [$($chain)* ($($operand)+) <=] $($rest)+)
+    };
+    (@parse $src:tt [$($chain:tt)*] [$($operand:tt)+] > $($rest:tt)+) => {
+        $crate::assert_cmp!(@push $src > [$($chain)* ($($operand)+) >] $($rest)+)
+    };
+    (@parse $src:tt [$($chain:tt)*] [$($operand:tt)+] >= $($rest:tt)+) => {
+        $crate::assert_cmp!(@push $src >= [$($chain)* ($($operand)+) >=] $($rest)+)
+    };
+    (@parse $src:tt [$($chain:tt)*] [$($operand:tt)+] == $($rest:tt)+) => {
+        $crate::assert_cmp!(@push $src == [$($chain)* ($($operand)+) ==] $($rest)+)
+    };
+    (@parse $src:tt [$($chain:tt)*] [$($operand:tt)+] != $($rest:tt)+) => {
+        $crate::assert_cmp!(@push $src != [$($chain)* ($($operand)+) !=] $($rest)+)
+    };
+    (@parse $src:tt [$($chain:tt)*] [$($operand:tt)+] ,) => {
+        $crate::assert_cmp!(@eval $src [] [] [$($chain)* ($($operand)+)] ())
+    };
+    (@parse $src:tt [$($chain:tt)*] [$($operand:tt)+] , $($arg:tt)+) => {
+        $crate::assert_cmp!(@eval $src [] [] [$($chain)* ($($operand)+)] ($($arg)+))
+    };
+    (@parse $src:tt [$($chain:tt)*] [$($operand:tt)+]) => {
+        $crate::assert_cmp!(@eval $src [] [] [$($chain)* ($($operand)+)] ())
+    };
+    (@parse $src:tt $chain:tt [$($operand:tt)+] && $($rest:tt)*) => {
+        compile_error!("assert_cmp! operands containing `&&` need to be wrapped in parentheses")
+    };
+    (@parse $src:tt $chain:tt [$($operand:tt)+] || $($rest:tt)*) => {
+        compile_error!("assert_cmp! operands containing `||` need to be wrapped in parentheses")
+    };
+    // Moves the tokens in front of the next operator into the operand at once,
+    // or four tokens at a time when there is no operator among them,
+    // keeping the recursion depth low for long operands.
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt < $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1] $chain [$($operand)* $t1] < $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt <= $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1] $chain [$($operand)* $t1] <= $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt > $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1] $chain [$($operand)* $t1] > $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt >= $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1] $chain [$($operand)* $t1] >= $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt == $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1] $chain [$($operand)* $t1] == $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt != $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1] $chain [$($operand)* $t1] != $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt , $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1] $chain [$($operand)* $t1] , $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt && $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1] $chain [$($operand)* $t1] && $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt || $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1] $chain [$($operand)* $t1] || $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt < $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2] $chain [$($operand)* $t1 $t2] < $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt <= $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2] $chain [$($operand)* $t1 $t2] <= $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt > $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2] $chain [$($operand)* $t1 $t2] > $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt >= $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2] $chain [$($operand)* $t1 $t2] >= $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt == $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2] $chain [$($operand)* $t1 $t2] == $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt != $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2] $chain [$($operand)* $t1 $t2] != $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt , $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2] $chain [$($operand)* $t1 $t2] , $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt && $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2] $chain [$($operand)* $t1 $t2] && $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt || $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2] $chain [$($operand)* $t1 $t2] || $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt $t3:tt < $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2 $t3] $chain [$($operand)* $t1 $t2 $t3] < $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt $t3:tt <= $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2 $t3] $chain [$($operand)* $t1 $t2 $t3] <= $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt $t3:tt > $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2 $t3] $chain [$($operand)* $t1 $t2 $t3] > $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt $t3:tt >= $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2 $t3] $chain [$($operand)* $t1 $t2 $t3] >= $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt $t3:tt == $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2 $t3] $chain [$($operand)* $t1 $t2 $t3] == $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt $t3:tt != $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2 $t3] $chain [$($operand)* $t1 $t2 $t3] != $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt $t3:tt , $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2 $t3] $chain [$($operand)* $t1 $t2 $t3] , $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt $t3:tt && $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2 $t3] $chain [$($operand)* $t1 $t2 $t3] && $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt $t3:tt || $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2 $t3] $chain [$($operand)* $t1 $t2 $t3] || $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $t1:tt $t2:tt $t3:tt $t4:tt $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $t1 $t2 $t3 $t4] $chain [$($operand)* $t1 $t2 $t3 $t4] $($rest)*)
+    };
+    (@parse [$($src:tt)*] $chain:tt [$($operand:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_cmp!(@parse [$($src)* $next] $chain [$($operand)* $next] $($rest)*)
+    };
+    (@push [$($src:tt)*] $op:tt $chain:tt $($rest:tt)+) => {
+        $crate::assert_cmp!(@parse [$($src)* $op] $chain [] $($rest)+)
+    };
+
+    // Evaluates every operand once, binding each of them to a reference.
+    (@eval $src:tt [] [] [$single:tt] $msg:tt) => {
+        compile_error!("assert_cmp! requires at least one comparison operator")
+    };
+    (@eval $src:tt [$($operands:tt)*] [$($links:tt)*] [($($operand:tt)+) $($rest:tt)*] $msg:tt) => {
+        match &($($operand)+) {
+            value => $crate::assert_cmp!(
+                @eval $src [$($operands)* (value ($($operand)+))] [$($links)* (value ($($operand)+))] [$($rest)*] $msg
+            ),
+        }
+    };
+    (@eval $src:tt $operands:tt [$($links:tt)*] [$op:tt $($rest:tt)*] $msg:tt) => {
+        $crate::assert_cmp!(@eval $src $operands [$($links)* $op] [$($rest)*] $msg)
+    };
+    (@eval $src:tt $operands:tt $links:tt [] $msg:tt) => {
+        $crate::assert_cmp!(@check $src $operands $links $msg)
+    };
+
+    // Checks every link of the chain in order, panicking at the first one that does not hold.
+    (@check $src:tt $operands:tt [($left:ident $left_expr:tt) $op:tt ($right:ident $right_expr:tt) $($rest:tt)*] $msg:tt) => {{
+        if !(*$left $op *$right) {
+            $crate::assert_cmp!(@panic $src $operands ($left_expr $op $right_expr) $msg)
+        }
+        $crate::assert_cmp!(@check $src $operands [($right $right_expr) $($rest)*] $msg)
+    }};
+    (@check $src:tt $operands:tt [$last:tt] $msg:tt) => {
+        ()
+    };
+
+    (@panic [$($src:tt)*] [$(($value:ident ($($operand:tt)+)))+] (($($left:tt)+) $op:tt ($($right:tt)+)) ()) => {
+        panic!(
+            concat!("assertion failed: `({})`\n    failed link: `{} {} {}`" $(, $crate::assert_cmp!(@line $value))+),
+            stringify!($($src)*),
+            stringify!($($left)+),
+            stringify!($op),
+            stringify!($($right)+)
+            $(, stringify!($($operand)+), $crate::__debug_value!(*$value))+
+        )
+    };
+    (@panic [$($src:tt)*] [$(($value:ident ($($operand:tt)+)))+] (($($left:tt)+) $op:tt ($($right:tt)+)) ($($arg:tt)+)) => {
+        panic!(
+            concat!("assertion failed: `({})`\n    failed link: `{} {} {}`" $(, $crate::assert_cmp!(@line $value))+, ": {}"),
+            stringify!($($src)*),
+            stringify!($($left)+),
+            stringify!($op),
+            stringify!($($right)+)
+            $(, stringify!($($operand)+), $crate::__debug_value!(*$value))+,
+            format_args!($($arg)+)
+        )
+    };
+    (@line $value:ident) => {
+        ",\n    {}: `{:?}`"
+    };
+
+    ($($tokens:tt)+) => {
+        $crate::assert_cmp!(@parse [] [] [] $($tokens)+)
+    };
+}
+
+/// Asserts that a chain of comparisons holds in runtime.
+///
+/// Like [`assert_cmp!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_cmp!`]: ./macro.assert_cmp.html
+#[macro_export]
+macro_rules! debug_assert_cmp {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_cmp!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(min <= x < max)`
+    failed link: `x < max`,
+    min: `1`,
+    x: `5`,
+    max: `5`"#)]
+    fn default_panic_message() {
+        let (min, x, max) = (1, 5, 5);
+        assert_cmp!(min <= x < max);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(0 == 1 - 1 != 0)`
+    failed link: `1 - 1 != 0`,
+    0: `0`,
+    1 - 1: `0`,
+    0: `0`: checking 0"#)]
+    fn custom_panic_message() {
+        assert_cmp!(0 == 1 - 1 != 0, "checking {}", 0);
+    }
+
+    #[test]
+    fn evaluates_operands_once() {
+        let mut calls = 0;
+        {
+            let mut next = || {
+                calls += 1;
+                calls
+            };
+            assert_cmp!(next() < next() <= next() > (next() - 4));
+        }
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn long_operands() {
+        assert_cmp!(0 < 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 == 80);
+    }
+}
@@ -0,0 +1,127 @@
+/// Asserts that [`Ord::cmp`] of the first expression with the second one returns the given [`Ordering`].
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Requires that both expressions be of the same type implementing [`Ord`].
+/// See [`assert_ordering!`] for also checking that `partial_cmp` agrees with `cmp`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_cmp_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// use std::cmp::Ordering;
+///
+/// # fn main() {
+/// assert_cmp_eq!(2, 1, Ordering::Greater);
+/// assert_cmp_eq!("a", "a", Ordering::Equal);
+///
+/// // With custom messages
+/// assert_cmp_eq!(1, 2, Ordering::Less, "Expecting {} to come before {}", 1, 2);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// use std::cmp::Ordering;
+///
+/// # fn main() {
+/// assert_cmp_eq!(1, 2, Ordering::Greater);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ord::cmp`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html#tymethod.cmp
+/// [`Ordering`]: https://doc.rust-lang.org/core/cmp/enum.Ordering.html
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`assert_ordering!`]: ./macro.assert_ordering.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_cmp_eq!`]: ./macro.debug_assert_cmp_eq.html
+#[macro_export]
+macro_rules! assert_cmp_eq {
+    ($left:expr, $right:expr, $expected:expr,) => {
+        $crate::assert_cmp_eq!($left, $right, $expected);
+    };
+    ($left:expr, $right:expr, $expected:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let expected: $crate::__private::Ordering = $expected;
+                let actual = $crate::__private::Ord::cmp(left_val, right_val);
+                if actual != expected {
+                    panic!(r#"assertion failed: `(left.cmp(right) == {:?})`
+    left: `{:?}`,
+    right: `{:?}`,
+    ordering: `{:?}`"#, expected, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), actual)
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $expected:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let expected: $crate::__private::Ordering = $expected;
+                let actual = $crate::__private::Ord::cmp(left_val, right_val);
+                if actual != expected {
+                    panic!(r#"assertion failed: `(left.cmp(right) == {:?})`
+    left: `{:?}`,
+    right: `{:?}`,
+    ordering: `{:?}`: {}"#, expected, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), actual, format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that [`Ord::cmp`] of the first expression with the second one returns the given [`Ordering`] in runtime.
+///
+/// Like [`assert_cmp_eq!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_cmp_eq!`]: ./macro.assert_cmp_eq.html
+#[macro_export]
+macro_rules! debug_assert_cmp_eq {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_cmp_eq!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::cmp::Ordering;
+
+    #[test]
+    fn equal_ordering() {
+        assert_cmp_eq!("b", "a", Ordering::Greater);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left.cmp(right) == Greater)`
+    left: `1`,
+    right: `2`,
+    ordering: `Less`"#)]
+    fn default_panic_message() {
+        assert_cmp_eq!(1, 2, Ordering::Greater);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left.cmp(right) == Less)`
+    left: `2`,
+    right: `2`,
+    ordering: `Equal`: comparing 2 to 2"#)]
+    fn custom_panic_message() {
+        assert_cmp_eq!(2, 2, Ordering::Less, "comparing {} to {}", 2, 2);
+    }
+}
@@ -0,0 +1,145 @@
+/// Asserts that the [`Display`] output of the expression contains the given text.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// The output is searched as it is being formatted, so that it never needs to be stored,
+/// which makes this macro available without an allocator. On panic, the value is formatted
+/// again to print the whole output.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_display_contains!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let addr = std::net::Ipv4Addr::new(192, 168, 0, 1);
+///
+/// assert_display_contains!(addr, "168.0");
+///
+/// // With custom messages
+/// assert_display_contains!(addr, "192.", "expecting a private address, got {}", addr);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_display_contains!(42, "7");  // Will panic
+/// # }
+/// ```
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_display_contains!`]: ./macro.debug_assert_display_contains.html
+#[macro_export]
+macro_rules! assert_display_contains {
+    ($value:expr, $needle:expr,) => {
+        $crate::assert_display_contains!($value, $needle);
+    };
+    ($value:expr, $needle:expr) => {
+        match (&$value, &$needle) {
+            (value, needle) => {
+                if !$crate::__private::display_contains(value, needle) {
+                    panic!(r#"assertion failed: `(display contains needle)`
+    display: `{}`,
+    needle: `{:?}`"#, value, needle);
+                }
+            }
+        }
+    };
+    ($value:expr, $needle:expr, $($arg:tt)+) => {
+        match (&$value, &$needle) {
+            (value, needle) => {
+                if !$crate::__private::display_contains(value, needle) {
+                    panic!(r#"assertion failed: `(display contains needle)`
+    display: `{}`,
+    needle: `{:?}`: {}"#, value, needle, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the [`Display`] output of the expression contains the given text in runtime.
+///
+/// Like [`assert_display_contains!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_display_contains!`]: ./macro.assert_display_contains.html
+#[macro_export]
+macro_rules! debug_assert_display_contains {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_display_contains!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+#[cfg(rustc_1_26)]
+mod tests {
+    use core::fmt;
+
+    /// Writes its output in pieces, so that matches can span more than one of them.
+    struct Pieces(&'static [&'static str]);
+
+    impl fmt::Display for Pieces {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for piece in self.0 {
+                tri!(f.write_str(piece));
+            }
+            Ok(())
+        }
+    }
+
+    /// Keeps writing its output after an error.
+    struct IgnoresErrors(&'static [&'static str]);
+
+    impl fmt::Display for IgnoresErrors {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for piece in self.0 {
+                let _ = f.write_str(piece);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn contains() {
+        assert_display_contains!(Pieces(&["connection ti", "m", "ed out"]), "timed");
+        assert_display_contains!(Pieces(&["aab", "aaab"]), "aaab");
+        assert_display_contains!(Pieces(&["ab", "abac"]), "abac");
+        assert_display_contains!(Pieces(&[]), "");
+        assert_display_contains!(-1.5, "-1.5",);
+        assert_display_contains!(IgnoresErrors(&["ab", "c"]), "ab");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(display contains needle)`
+    display: `connection refused`,
+    needle: `"timed out"`"#)]
+    fn default_panic_message() {
+        assert_display_contains!(Pieces(&["connection ", "refused"]), "timed out");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(display contains needle)`
+    display: `aababa`,
+    needle: `"abab "`: checking ab"#)]
+    fn custom_panic_message() {
+        assert_display_contains!(Pieces(&["aab", "aba"]), "abab ", "checking {}", "ab");
+    }
+}
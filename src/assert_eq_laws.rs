@@ -0,0 +1,159 @@
+/// Asserts that the [`PartialEq`] implementation of the given sample values obeys its laws.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Accepts anything that can be sliced with `[..]`, such as arrays, slices and vectors,
+/// and requires the values to implement [`PartialEq`] and [`Debug`].
+///
+/// Checks that `==` is symmetric and transitive, and that `!=` is its negation.
+/// If the values also implement [`Eq`], checks that every value is equal to itself.
+///
+/// Every pair and triple of samples is checked. On failure, the smallest counterexample
+/// is reported along with the violated law.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_eq_laws!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_eq_laws!(["a", "b", "a"]);
+/// assert_eq_laws!([1.0, "NaN".parse::<f64>().unwrap(), 1.0]);
+///
+/// // With custom messages
+/// assert_eq_laws!([Some(1), None], "Checking {}", "options");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// #[derive(Debug)]
+/// struct Close(i32);
+///
+/// impl PartialEq for Close {
+///     fn eq(&self, other: &Self) -> bool {
+///         (self.0 - other.0).abs() <= 1
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_eq_laws!([Close(0), Close(1), Close(2)]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Eq`]: https://doc.rust-lang.org/core/cmp/trait.Eq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_eq_laws!`]: ./macro.debug_assert_eq_laws.html
+#[macro_export]
+macro_rules! assert_eq_laws {
+    ($samples:expr,) => {
+        $crate::assert_eq_laws!($samples);
+    };
+    ($samples:expr) => {
+        match &$samples {
+            samples => {
+                use $crate::__private::CheckEqLaws;
+                if let Err(violation) = (&$crate::__private::EqSamples(&samples[..])).check_eq_laws() {
+                    panic!("assertion failed, `PartialEq` laws do not hold: {}", violation);
+                }
+            }
+        }
+    };
+    ($samples:expr, $($arg:tt)+) => {
+        match &$samples {
+            samples => {
+                use $crate::__private::CheckEqLaws;
+                if let Err(violation) = (&$crate::__private::EqSamples(&samples[..])).check_eq_laws() {
+                    panic!("assertion failed, `PartialEq` laws do not hold: {}: {}", violation, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the [`PartialEq`] implementation of the given sample values obeys its laws in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_eq_laws!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_eq_laws!`]: ./macro.assert_eq_laws.html
+#[macro_export]
+macro_rules! debug_assert_eq_laws {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_eq_laws!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    struct Close(i32);
+
+    impl PartialEq for Close {
+        fn eq(&self, other: &Self) -> bool {
+            (self.0 - other.0).abs() <= 1
+        }
+    }
+
+    #[derive(Debug)]
+    struct Never;
+
+    impl PartialEq for Never {
+        fn eq(&self, _: &Self) -> bool {
+            false
+        }
+    }
+
+    impl Eq for Never {}
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, `PartialEq` laws do not hold: transitivity, `a == b` and `b == c` but not `a == c`
+    a: `Close(0)` (at index 0),
+    b: `Close(1)` (at index 1),
+    c: `Close(2)` (at index 2),
+    a == b: `true`,
+    b == c: `true`,
+    a == c: `false`"#
+    )]
+    fn default_panic_message() {
+        assert_eq_laws!([Close(0), Close(1), Close(2)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, `PartialEq` laws do not hold: reflexivity, `a == a` does not hold for an `Eq` type
+    a: `Never` (at index 0),
+    b: `Never` (at index 0),
+    a == b: `false`,
+    b == a: `false`,
+    a != b: `true`: checking Never"#
+    )]
+    fn custom_panic_message() {
+        assert_eq_laws!([Never], "checking {:?}", Never);
+    }
+
+    #[test]
+    fn partial_eq_is_not_reflexive() {
+        assert_eq_laws!(["NaN".parse::<f64>().unwrap()]);
+    }
+}
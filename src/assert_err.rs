@@ -38,6 +38,31 @@
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
 /// [`debug_assert_err!`]: ./macro.debug_assert_err.html
+#[cfg(rustc_1_30)]
+#[macro_export]
+macro_rules! assert_err {
+    ($cond:expr,) => {
+        $crate::assert_err!($cond);
+    };
+    ($cond:expr) => {
+        match $cond {
+            Ok(t) => {
+                panic!("assertion failed, expected Err(..), got Ok({:?})", $crate::__debug_value!(t));
+            },
+            Err(e) => e,
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        match $cond {
+            Ok(t) => {
+                panic!("assertion failed, expected Err(..), got Ok({:?}): {}", $crate::__debug_value!(t), format_args!($($arg)+));
+            },
+            Err(e) => e,
+        }
+    };
+}
+
+#[cfg(not(rustc_1_30))]
 #[macro_export]
 macro_rules! assert_err {
     ($cond:expr,) => {
@@ -0,0 +1,148 @@
+/// Asserts that expression returns [`Err(E)`] variant, whose [`Display`] output contains
+/// the given text.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_display_contains!`], the output is searched as it is being formatted,
+/// which makes this macro available without an allocator.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_err_contains!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_err_contains!("256".parse::<u8>(), "too large");
+///
+/// // With custom messages
+/// assert_err_contains!("256".parse::<u8>(), "too large", "expecting {} to overflow", 256);
+/// # }
+/// ```
+///
+/// Value of `E` type from `Err(E)` will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<u8, _> = "256".parse::<u8>();
+///
+/// let error = assert_err_contains!(res, "too large");
+/// assert_eq!(error, "1000".parse::<u8>().unwrap_err());
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<u8, _> = "-1".parse::<u8>();
+///
+/// assert_err_contains!(res, "too large");  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`assert_display_contains!`]: ./macro.assert_display_contains.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_err_contains!`]: ./macro.debug_assert_err_contains.html
+#[macro_export]
+macro_rules! assert_err_contains {
+    ($cond:expr, $needle:expr,) => {
+        $crate::assert_err_contains!($cond, $needle)
+    };
+    ($cond:expr, $needle:expr) => {
+        match ($cond, &$needle) {
+            (Ok(t), _) => {
+                panic!("assertion failed, expected Err(..), got Ok({:?})", $crate::__debug_value!(t));
+            },
+            (Err(e), needle) => {
+                if !$crate::__private::display_contains(&e, needle) {
+                    panic!(r#"assertion failed: `(error contains needle)`
+    error: `{}`,
+    needle: `{:?}`"#, e, needle);
+                }
+                e
+            }
+        }
+    };
+    ($cond:expr, $needle:expr, $($arg:tt)+) => {
+        match ($cond, &$needle) {
+            (Ok(t), _) => {
+                panic!("assertion failed, expected Err(..), got Ok({:?}): {}", $crate::__debug_value!(t), format_args!($($arg)+));
+            },
+            (Err(e), needle) => {
+                if !$crate::__private::display_contains(&e, needle) {
+                    panic!(r#"assertion failed: `(error contains needle)`
+    error: `{}`,
+    needle: `{:?}`: {}"#, e, needle, format_args!($($arg)+));
+                }
+                e
+            }
+        }
+    };
+}
+
+/// Asserts that expression returns [`Err(E)`] variant, whose [`Display`] output contains
+/// the given text in runtime.
+///
+/// Like [`assert_err_contains!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_err_contains!`]: ./macro.assert_err_contains.html
+#[macro_export]
+macro_rules! debug_assert_err_contains {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_err_contains!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+#[cfg(rustc_1_26)]
+mod tests {
+    use core::fmt;
+
+    #[derive(Debug, PartialEq)]
+    struct Timeout(u32);
+
+    impl fmt::Display for Timeout {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "operation timed out after {}ms", self.0)
+        }
+    }
+
+    #[test]
+    fn returns_error() {
+        let res: Result<(), Timeout> = Err(Timeout(500));
+        assert_eq!(assert_err_contains!(res, "after 500ms"), Timeout(500));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Err(..), got Ok(1)")]
+    fn not_an_error() {
+        let _ = assert_err_contains!(Ok::<_, Timeout>(1), "timed out");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(error contains needle)`
+    error: `operation timed out after 500ms`,
+    needle: `"refused"`: checking 500"#)]
+    fn custom_panic_message() {
+        let _ = assert_err_contains!(Err::<(), _>(Timeout(500)), "refused", "checking {}", 500);
+    }
+}
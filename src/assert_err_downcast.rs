@@ -0,0 +1,124 @@
+/// Asserts that expression returns [`Err(E)`] variant, whose boxed error is of the given type.
+///
+/// This macro is available for Rust 1.30+, with the `std` feature enabled.
+///
+/// It works for errors that can be downcast by reference, such as `Box<dyn Error>`,
+/// `Box<dyn Error + Send + Sync>` or `Box<dyn Any>`, and returns a reference
+/// to the concrete error. As the reference borrows the error, the expression has to be
+/// a place, such as a variable, rather than a temporary value.
+///
+/// On panic, the error is printed with its [`Debug`] implementation and, for errors
+/// implementing [`Display`] such as `Box<dyn Error>`, with its [`Display`] output too.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_err_downcast!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// use std::error::Error;
+/// use std::num::ParseIntError;
+///
+/// fn parse(input: &str) -> Result<u8, Box<dyn Error + Send + Sync>> {
+///     Ok(input.parse()?)
+/// }
+///
+/// # fn main() {
+/// let res = parse("256");
+///
+/// let error: &ParseIntError = assert_err_downcast!(res, ParseIntError);
+/// assert_eq!(error.to_string(), "number too large to fit in target type");
+///
+/// // With custom messages
+/// assert_err_downcast!(res, ParseIntError, "expecting {} to overflow", 256);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # use std::error::Error;
+/// # fn main() {
+/// let res: Result<(), Box<dyn Error>> = Err("not a number".into());
+///
+/// assert_err_downcast!(res, std::num::ParseIntError);  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_err_downcast!`]: ./macro.debug_assert_err_downcast.html
+#[macro_export]
+macro_rules! assert_err_downcast {
+    ($cond:expr, $type:ty,) => {
+        $crate::assert_err_downcast!($cond, $type)
+    };
+    ($cond:expr, $type:ty) => {
+        match $cond {
+            Ok(ref t) => {
+                panic!("assertion failed, expected Err(..), got Ok({:?})", $crate::__debug_value!(t));
+            },
+            Err(ref e) => match e.downcast_ref::<$type>() {
+                Some(e) => e,
+                None => {
+                    use $crate::__private::DisplayValue;
+                    panic!(r#"assertion failed, error cannot be downcast to `{}`
+    error: {:?}{}"#, stringify!($type), $crate::__debug_value!(e), (&$crate::__private::MaybeDisplay(e)).display_value());
+                }
+            },
+        }
+    };
+    ($cond:expr, $type:ty, $($arg:tt)+) => {
+        match $cond {
+            Ok(ref t) => {
+                panic!("assertion failed, expected Err(..), got Ok({:?}): {}", $crate::__debug_value!(t), format_args!($($arg)+));
+            },
+            Err(ref e) => match e.downcast_ref::<$type>() {
+                Some(e) => e,
+                None => {
+                    use $crate::__private::DisplayValue;
+                    panic!(r#"assertion failed, error cannot be downcast to `{}`
+    error: {:?}{}: {}"#, stringify!($type), $crate::__debug_value!(e), (&$crate::__private::MaybeDisplay(e)).display_value(), format_args!($($arg)+));
+                }
+            },
+        }
+    };
+}
+
+/// Asserts that expression returns [`Err(E)`] variant, whose boxed error is of the given type
+/// in runtime.
+///
+/// This macro is available for Rust 1.30+, with the `std` feature enabled.
+///
+/// Like [`assert_err_downcast!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_err_downcast!`]: ./macro.assert_err_downcast.html
+#[macro_export]
+macro_rules! debug_assert_err_downcast {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_err_downcast!($($arg)*); })
+}
+
+// Rust 1.31 to 1.34 reject `dyn` in macro input under `#![forbid(future_incompatible)]`,
+// and Rust 1.10 and older in any file they parse, so the tests live in their own file.
+#[cfg(test)]
+#[cfg(rustc_1_35)]
+newer_syntax! {
+    mod tests;
+}
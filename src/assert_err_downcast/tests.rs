@@ -0,0 +1,72 @@
+use std::any::Any;
+use std::boxed::Box;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+struct Timeout(u32);
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timed out after {}ms", self.0)
+    }
+}
+
+impl Error for Timeout {}
+
+#[derive(Debug)]
+struct Refused;
+
+impl fmt::Display for Refused {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("connection refused")
+    }
+}
+
+impl Error for Refused {}
+
+#[test]
+fn downcasts() {
+    let res: Result<(), Box<dyn Error + Send + Sync>> = Err(Box::new(Timeout(500)));
+    assert_eq!(assert_err_downcast!(res, Timeout), &Timeout(500));
+
+    let res: Result<(), Box<dyn Error>> = Err(Box::new(Timeout(500)));
+    assert_eq!(assert_err_downcast!(res, Timeout,), &Timeout(500));
+
+    let res: Result<(), Box<dyn Any + Send>> = Err(Box::new("payload"));
+    assert_eq!(*assert_err_downcast!(res, &str), "payload");
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed, error cannot be downcast to `Timeout`
+    error: Refused
+    display: connection refused"#)]
+fn default_panic_message() {
+    let res: Result<(), Box<dyn Error + Send + Sync>> = Err(Box::new(Refused));
+    let _ = assert_err_downcast!(res, Timeout);
+}
+
+#[test]
+// Older compilers print `dyn Any` as `Any` rather than `Any { .. }`.
+#[should_panic(expected = r#"assertion failed, error cannot be downcast to `Timeout`
+    error: Any"#)]
+fn without_display() {
+    let res: Result<(), Box<dyn Any + Send>> = Err(Box::new("payload"));
+    let _ = assert_err_downcast!(res, Timeout);
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed, error cannot be downcast to `Timeout`
+    error: Refused
+    display: connection refused: checking 500"#)]
+fn custom_panic_message() {
+    let res: Result<(), Box<dyn Error>> = Err(Box::new(Refused));
+    let _ = assert_err_downcast!(res, Timeout, "checking {}", 500);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed, expected Err(..), got Ok(1)")]
+fn not_an_error() {
+    let res: Result<u8, Box<dyn Error>> = Ok(1);
+    let _ = assert_err_downcast!(res, Timeout);
+}
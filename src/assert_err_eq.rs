@@ -51,7 +51,37 @@
 /// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_err_eq!`]: ./macro.debug_assert_err_eq.html
-#[cfg(rustc_1_11)]
+#[cfg(rustc_1_30)]
+#[macro_export]
+macro_rules! assert_err_eq {
+    ($cond:expr, $expected:expr,) => {
+        $crate::assert_err_eq!($cond, $expected);
+    };
+    ($cond:expr, $expected:expr) => {
+        match $cond {
+            Err(t) => {
+                assert_eq!(t, $expected);
+                t
+            },
+            ok @ Ok(..) => {
+                panic!("assertion failed, expected Err(..), got {:?}", $crate::__debug_value!(ok));
+            }
+        }
+    };
+    ($cond:expr, $expected:expr, $($arg:tt)+) => {
+        match $cond {
+            Err(t) => {
+                assert_eq!(t, $expected, $($arg)+);
+                t
+            },
+            ok @ Ok(..) => {
+                panic!("assertion failed, expected Err(..), got {:?}: {}", $crate::__debug_value!(ok), format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+#[cfg(all(rustc_1_11, not(rustc_1_30)))]
 #[macro_export]
 macro_rules! assert_err_eq {
     ($cond:expr, $expected:expr,) => {
@@ -141,4 +171,19 @@ mod tests {
     fn custom_message_propagation() {
         let _ = assert_err_eq!(Err::<(), _>(1), 2, "foo");
     }
+
+    #[test]
+    #[cfg(rustc_1_31)]
+    #[cfg_attr(
+        not(rustc_1_38),
+        ignore = "type names are only available in rustc 1.38.0 or later"
+    )]
+    #[should_panic(
+        expected = "assertion failed, expected Err(..), got <core::result::Result<claims::assert_err_eq::tests::does_not_require_ok_debug::Foo, i32>: no Debug impl>"
+    )]
+    fn does_not_require_ok_debug() {
+        struct Foo;
+
+        let _ = assert_err_eq!(Ok::<_, i32>(Foo), 1);
+    }
 }
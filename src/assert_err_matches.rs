@@ -0,0 +1,177 @@
+/// Asserts that expression returns [`Err(E)`] variant, and that `E` matches any of the given variants.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// It works like [`assert_matches!`] applied to the value inside of `Err(..)`,
+/// and supports its guards and bindings: the pattern can be followed by `=> expression`,
+/// which is evaluated with the bindings of the pattern in scope and returned by the macro.
+///
+/// The panic message tells whether the expression is an `Ok(..)` variant,
+/// or whether the value inside of `Err(..)` does not match the pattern.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_err_matches!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// #[derive(Debug)]
+/// enum Error {
+///     NotFound { id: u32 },
+///     Timeout,
+/// }
+///
+/// # fn main() {
+/// let res: Result<(), Error> = Err(Error::NotFound { id: 42 });
+///
+/// assert_err_matches!(&res, Error::NotFound { .. });
+///
+/// // With custom messages
+/// assert_err_matches!(&res, Error::NotFound { .. }, "expecting missing {:?}", res);
+///
+/// // Returning the bindings
+/// let id = assert_err_matches!(res, Error::NotFound { id } if id > 0 => id);
+/// assert_eq!(id, 42);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # #[derive(Debug)]
+/// # enum Error {
+/// #     NotFound { id: u32 },
+/// #     Timeout,
+/// # }
+/// # fn main() {
+/// let res: Result<(), Error> = Err(Error::Timeout);
+///
+/// assert_err_matches!(res, Error::NotFound { .. });  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_err_matches!`]: ./macro.debug_assert_err_matches.html
+#[macro_export]
+macro_rules! assert_err_matches {
+    (@err $expression:expr, $variants:tt, $result:tt, [$($arg:tt)*]) => {
+        match $expression {
+            Ok(t) => {
+                $crate::assert_matches!(@panic ["assertion failed, expected Err(..), got Ok({:?})", $crate::__debug_value!(t)] [$($arg)*])
+            }
+            Err(value) => $crate::assert_matches!(@unwrapped "Err", value, $variants, $result, [$($arg)*]),
+        }
+    };
+
+    ($expression:expr, $( $pattern:pat )|+) => {
+        $crate::assert_err_matches!(@err $expression, [$($pattern)|+], [()], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr) => {
+        $crate::assert_err_matches!(@err $expression, [$($pattern)|+ if $guard], [()], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+, $($arg:tt)+) => {
+        $crate::assert_err_matches!(@err $expression, [$($pattern)|+], [()], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr, $($arg:tt)+) => {
+        $crate::assert_err_matches!(@err $expression, [$($pattern)|+ if $guard], [()], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ => $result:expr) => {
+        $crate::assert_err_matches!(@err $expression, [$($pattern)|+], [$result], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr => $result:expr) => {
+        $crate::assert_err_matches!(@err $expression, [$($pattern)|+ if $guard], [$result], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ => $result:expr, $($arg:tt)+) => {
+        $crate::assert_err_matches!(@err $expression, [$($pattern)|+], [$result], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr => $result:expr, $($arg:tt)+) => {
+        $crate::assert_err_matches!(@err $expression, [$($pattern)|+ if $guard], [$result], [$($arg)+])
+    };
+}
+
+/// Asserts that expression returns [`Err(E)`] variant, and that `E` matches any of the given variants
+/// in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_err_matches!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_err_matches!`]: ./macro.assert_err_matches.html
+#[macro_export]
+macro_rules! debug_assert_err_matches {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_err_matches!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    enum Error {
+        NotFound { id: u32, name: &'static str },
+        Timeout,
+    }
+
+    #[test]
+    fn returns_bindings() {
+        let res: Result<(), Error> = Err(Error::NotFound {
+            id: 7,
+            name: "alice",
+        });
+        let (id, name) =
+            assert_err_matches!(res, Error::NotFound { id, name } if id > 0 => (id, name));
+        assert_eq!((id, name), (7, "alice"));
+
+        assert_err_matches!(
+            Err::<(), _>(Error::Timeout),
+            Error::Timeout | Error::NotFound { id: 0, .. }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Err(..), got Ok(1)")]
+    fn wrong_variant() {
+        assert_err_matches!(Ok::<_, Error>(1), Error::Timeout);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, Err value does not match any of the given variants.
+    value: Timeout
+    variants: Error::NotFound { .. }"#
+    )]
+    fn pattern_mismatch() {
+        assert_err_matches!(Err::<(), _>(Error::Timeout), Error::NotFound { .. });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, Err value matches one of the given variants, but the guard does not hold.
+    value: NotFound { id: 7, name: "alice" }
+    variant: Error::NotFound { id, .. }
+    guard: id > 10: checking alice"#
+    )]
+    fn guard_panic_message() {
+        let res: Result<(), Error> = Err(Error::NotFound {
+            id: 7,
+            name: "alice",
+        });
+        assert_err_matches!(res, Error::NotFound { id, .. } if id > 10, "checking {}", "alice");
+    }
+}
@@ -0,0 +1,237 @@
+/// Asserts that expression returns [`Err(E)`] variant, whose chain of [`source`]s contains
+/// the expected causes.
+///
+/// This macro is available for Rust 1.37+, with the `std` feature enabled.
+///
+/// The chain starts with the error itself, followed by its source, the source of that one,
+/// and so on. Every expected cause is either a type, which matches causes of exactly that type,
+/// or a string literal, which matches causes whose [`Display`] output is exactly that string.
+///
+/// The expected causes have to appear in the chain in the given order, possibly with other
+/// causes between them. See [`assert_err_source_chain_exact!`] for requiring the chain
+/// to consist of exactly the expected causes instead.
+///
+/// On panic, the whole chain is printed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_err_source_chain!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// use std::error::Error;
+/// use std::fmt;
+/// use std::io;
+///
+/// #[derive(Debug)]
+/// struct ConfigError(io::Error);
+///
+/// impl fmt::Display for ConfigError {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         f.write_str("failed to load config")
+///     }
+/// }
+///
+/// impl Error for ConfigError {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// # fn main() {
+/// let load = || Err::<(), _>(ConfigError(io::Error::new(io::ErrorKind::Other, "disk on fire")));
+///
+/// assert_err_source_chain!(load(), [io::Error]);
+/// assert_err_source_chain!(load(), [ConfigError, "disk on fire"]);
+///
+/// // With custom messages
+/// assert_err_source_chain!(load(), ["disk on fire"], "expecting the {} to be reported", "fire");
+///
+/// // The error is returned
+/// let error = assert_err_source_chain!(load(), [ConfigError]);
+/// assert_eq!(error.to_string(), "failed to load config");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<u8, _> = "-1".parse::<u8>();
+///
+/// assert_err_source_chain!(res, [std::io::Error]);  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`source`]: https://doc.rust-lang.org/std/error/trait.Error.html#method.source
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+/// [`assert_err_source_chain_exact!`]: ./macro.assert_err_source_chain_exact.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_err_source_chain!`]: ./macro.debug_assert_err_source_chain.html
+#[macro_export]
+macro_rules! assert_err_source_chain {
+    (@causes [$($causes:expr,)*]) => {
+        [$($causes),*]
+    };
+    (@causes [$($causes:expr,)*] $message:literal $(, $($rest:tt)*)?) => {
+        $crate::assert_err_source_chain!(@causes [$($causes,)* $crate::__private::Cause::Message($message),] $($($rest)*)?)
+    };
+    (@causes [$($causes:expr,)*] $type:ty $(, $($rest:tt)*)?) => {
+        $crate::assert_err_source_chain!(@causes [$($causes,)* $crate::__private::Cause::of::<$type>(stringify!($type)),] $($($rest)*)?)
+    };
+    (@check $mode:ident, $cond:expr, [$($expected:tt)*]) => {
+        match $cond {
+            Ok(t) => {
+                panic!("assertion failed, expected Err(..), got Ok({:?})", $crate::__debug_value!(t));
+            },
+            Err(e) => {
+                if let Err(mismatch) = $crate::__private::check_source_chain(
+                    &e,
+                    $crate::__private::ChainMode::$mode,
+                    &$crate::assert_err_source_chain!(@causes [] $($expected)*),
+                ) {
+                    panic!("assertion failed, {}", mismatch);
+                }
+                e
+            }
+        }
+    };
+    (@check $mode:ident, $cond:expr, [$($expected:tt)*],) => {
+        $crate::assert_err_source_chain!(@check $mode, $cond, [$($expected)*])
+    };
+    (@check $mode:ident, $cond:expr, [$($expected:tt)*], $($arg:tt)+) => {
+        match $cond {
+            Ok(t) => {
+                panic!("assertion failed, expected Err(..), got Ok({:?}): {}", $crate::__debug_value!(t), format_args!($($arg)+));
+            },
+            Err(e) => {
+                if let Err(mismatch) = $crate::__private::check_source_chain(
+                    &e,
+                    $crate::__private::ChainMode::$mode,
+                    &$crate::assert_err_source_chain!(@causes [] $($expected)*),
+                ) {
+                    panic!("assertion failed, {}: {}", mismatch, format_args!($($arg)+));
+                }
+                e
+            }
+        }
+    };
+    ($cond:expr, [$($expected:tt)*] $($rest:tt)*) => {
+        $crate::assert_err_source_chain!(@check Contains, $cond, [$($expected)*] $($rest)*)
+    };
+}
+
+/// Asserts that expression returns [`Err(E)`] variant, whose chain of [`source`]s contains
+/// the expected causes in runtime.
+///
+/// This macro is available for Rust 1.37+, with the `std` feature enabled.
+///
+/// Like [`assert_err_source_chain!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`source`]: https://doc.rust-lang.org/std/error/trait.Error.html#method.source
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_err_source_chain!`]: ./macro.assert_err_source_chain.html
+#[macro_export]
+macro_rules! debug_assert_err_source_chain {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_err_source_chain!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use std::error::Error;
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct Timeout;
+
+    impl fmt::Display for Timeout {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("timed out")
+        }
+    }
+
+    impl Error for Timeout {}
+
+    #[derive(Debug)]
+    struct Wrapped(&'static str, Timeout);
+
+    impl fmt::Display for Wrapped {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    newer_syntax! {
+        impl Error for Wrapped {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.1)
+            }
+        }
+    }
+
+    fn request() -> Result<(), Wrapped> {
+        Err(Wrapped("request failed", Timeout))
+    }
+
+    #[test]
+    fn contains() {
+        let _ = assert_err_source_chain!(request(), []);
+        let _ = assert_err_source_chain!(request(), [Timeout]);
+        let error = assert_err_source_chain!(request(), ["request failed", "timed out"],);
+        assert_eq!(error.0, "request failed");
+    }
+
+    #[test]
+    fn values_named_like_the_other_form() {
+        let exact = request();
+        let _ = assert_err_source_chain!(exact, [Timeout]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, error source chain does not contain the expected causes in order.
+    chain:
+        [0]: `request failed`
+        [1]: `timed out`
+    expected: [Timeout, "request failed"]
+    missing: "request failed""#
+    )]
+    fn out_of_order() {
+        let _ = assert_err_source_chain!(request(), [Timeout, "request failed"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, error source chain does not contain the expected causes in order.
+    chain:
+        [0]: `request failed`
+        [1]: `timed out`
+    expected: ["refused"]
+    missing: "refused": checking request"#
+    )]
+    fn custom_panic_message() {
+        let _ = assert_err_source_chain!(request(), ["refused"], "checking {}", "request");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Err(..), got Ok(())")]
+    fn not_an_error() {
+        let _ = assert_err_source_chain!(Ok::<(), Wrapped>(()), [Timeout]);
+    }
+}
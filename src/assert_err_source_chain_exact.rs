@@ -0,0 +1,167 @@
+/// Asserts that expression returns [`Err(E)`] variant, whose chain of [`source`]s consists
+/// of exactly the expected causes.
+///
+/// This macro is available for Rust 1.37+, with the `std` feature enabled.
+///
+/// Works like [`assert_err_source_chain!`], except that the chain must not contain
+/// any other causes than the expected ones, in the given order.
+///
+/// On panic, the whole chain is printed.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_err_source_chain_exact!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// use std::error::Error;
+/// use std::fmt;
+/// use std::io;
+///
+/// #[derive(Debug)]
+/// struct ConfigError(io::Error);
+///
+/// impl fmt::Display for ConfigError {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         f.write_str("failed to load config")
+///     }
+/// }
+///
+/// impl Error for ConfigError {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// # fn main() {
+/// let load = || Err::<(), _>(ConfigError(io::Error::new(io::ErrorKind::Other, "disk on fire")));
+///
+/// assert_err_source_chain_exact!(load(), [ConfigError, "disk on fire"]);
+///
+/// // With custom messages
+/// assert_err_source_chain_exact!(load(), [ConfigError, io::Error], "expecting {} causes", 2);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<u8, _> = "-1".parse::<u8>();
+///
+/// assert_err_source_chain_exact!(res, []);  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`source`]: https://doc.rust-lang.org/std/error/trait.Error.html#method.source
+/// [`assert_err_source_chain!`]: ./macro.assert_err_source_chain.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_err_source_chain_exact!`]: ./macro.debug_assert_err_source_chain_exact.html
+#[macro_export]
+macro_rules! assert_err_source_chain_exact {
+    ($cond:expr, [$($expected:tt)*] $($rest:tt)*) => {
+        $crate::assert_err_source_chain!(@check Exact, $cond, [$($expected)*] $($rest)*)
+    };
+}
+
+/// Asserts that expression returns [`Err(E)`] variant, whose chain of [`source`]s consists
+/// of exactly the expected causes in runtime.
+///
+/// This macro is available for Rust 1.37+, with the `std` feature enabled.
+///
+/// Like [`assert_err_source_chain_exact!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`source`]: https://doc.rust-lang.org/std/error/trait.Error.html#method.source
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_err_source_chain_exact!`]: ./macro.assert_err_source_chain_exact.html
+#[macro_export]
+macro_rules! debug_assert_err_source_chain_exact {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_err_source_chain_exact!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use std::error::Error;
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct Timeout;
+
+    impl fmt::Display for Timeout {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("timed out")
+        }
+    }
+
+    impl Error for Timeout {}
+
+    #[derive(Debug)]
+    struct Wrapped(&'static str, Timeout);
+
+    impl fmt::Display for Wrapped {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    newer_syntax! {
+        impl Error for Wrapped {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.1)
+            }
+        }
+    }
+
+    fn request() -> Result<(), Wrapped> {
+        Err(Wrapped("request failed", Timeout))
+    }
+
+    #[test]
+    fn exact() {
+        let _ = assert_err_source_chain_exact!(request(), ["request failed", Timeout],);
+        let error = assert_err_source_chain_exact!(request(), [Wrapped, Timeout]);
+        assert_eq!(error.0, "request failed");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, error source chain does not equal the expected causes.
+    chain:
+        [0]: `request failed`
+        [1]: `timed out`
+    expected: [Wrapped, "timed out!"]
+    mismatch: [1] is not "timed out!""#
+    )]
+    fn default_panic_message() {
+        let _ = assert_err_source_chain_exact!(request(), [Wrapped, "timed out!"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, error source chain does not equal the expected causes.
+    chain:
+        [0]: `request failed`
+        [1]: `timed out`
+    expected: [Wrapped]
+    mismatch: expected 1 causes, got 2: checking request"#
+    )]
+    fn custom_panic_message() {
+        let _ = assert_err_source_chain_exact!(request(), [Wrapped], "checking {}", "request");
+    }
+}
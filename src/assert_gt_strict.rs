@@ -0,0 +1,92 @@
+/// Asserts that first expression is greater than the second, failing on unordered values.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Unlike [`assert_gt!`], this macro checks [`PartialOrd::partial_cmp`] explicitly,
+/// and fails when it returns `None` even if a custom `PartialOrd` implementation
+/// reports the values as `left > right`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_gt_strict!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_gt_strict!(2, 1);
+///
+/// // With custom messages
+/// assert_gt_strict!(2, 1, "Expecting that {} is greater than {}", 2, 1);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let nan = "NaN".parse::<f64>().unwrap();
+///
+/// assert_gt_strict!(nan, 1.0);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_gt!`]: ./macro.assert_gt.html
+/// [`PartialOrd::partial_cmp`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#tymethod.partial_cmp
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_gt_strict!`]: ./macro.debug_assert_gt_strict.html
+#[macro_export]
+macro_rules! assert_gt_strict {
+    ($left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if $crate::__private::PartialOrd::partial_cmp(left_val, right_val).is_none() {
+                    panic!(r#"assertion failed: `(left > right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val))
+                }
+                $crate::assert_gt!(*left_val, *right_val);
+            }
+        }
+    };
+    ($left:expr, $right:expr,) => {
+        $crate::assert_gt_strict!($left, $right);
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if $crate::__private::PartialOrd::partial_cmp(left_val, right_val).is_none() {
+                    panic!(r#"assertion failed: `(left > right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`: {}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), format_args!($($arg)+))
+                }
+                $crate::assert_gt!(*left_val, *right_val, $($arg)+);
+            }
+        }
+    };
+}
+
+/// Asserts that first expression is greater than the second, failing on unordered values in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_gt_strict!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_gt_strict!`]: ./macro.assert_gt_strict.html
+#[macro_export]
+macro_rules! debug_assert_gt_strict {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_gt_strict!($($arg)*); })
+}
@@ -0,0 +1,144 @@
+/// Asserts that the given sample values that are equal also have equal hashes.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Accepts anything that can be sliced with `[..]`, such as arrays, slices and vectors,
+/// and requires the values to implement [`Hash`], [`PartialEq`] and [`Debug`].
+///
+/// Values are hashed with a deterministic hasher built into this crate, so this macro
+/// works without `std`, and the printed hashes are the same on every run.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_hash_consistent!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_hash_consistent!(["a", "b", "a"]);
+///
+/// // With custom messages
+/// assert_hash_consistent!([Some(1), None, Some(1)], "Checking {}", "options");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// #[derive(Debug, Hash)]
+/// struct CaseInsensitive(&'static str);
+///
+/// impl PartialEq for CaseInsensitive {
+///     fn eq(&self, other: &Self) -> bool {
+///         self.0.eq_ignore_ascii_case(other.0)
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_hash_consistent!([CaseInsensitive("a"), CaseInsensitive("A")]);  // Will panic
+/// # }
+/// ```
+///
+/// [`Hash`]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_hash_consistent!`]: ./macro.debug_assert_hash_consistent.html
+#[macro_export]
+macro_rules! assert_hash_consistent {
+    ($samples:expr,) => {
+        $crate::assert_hash_consistent!($samples);
+    };
+    ($samples:expr) => {
+        match &$samples {
+            samples => {
+                if let Err(violation) = $crate::__private::check_hash_consistent(&samples[..]) {
+                    panic!("assertion failed, `Hash` is not consistent with `PartialEq`: {}", violation);
+                }
+            }
+        }
+    };
+    ($samples:expr, $($arg:tt)+) => {
+        match &$samples {
+            samples => {
+                if let Err(violation) = $crate::__private::check_hash_consistent(&samples[..]) {
+                    panic!("assertion failed, `Hash` is not consistent with `PartialEq`: {}: {}", violation, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the given sample values that are equal also have equal hashes in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_hash_consistent!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_hash_consistent!`]: ./macro.assert_hash_consistent.html
+#[macro_export]
+macro_rules! debug_assert_hash_consistent {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_hash_consistent!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::hash::{Hash, Hasher};
+
+    #[derive(Debug, PartialEq)]
+    struct Id(u8, u8);
+
+    impl Hash for Id {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            state.write_u8(self.1);
+        }
+    }
+
+    #[derive(Debug)]
+    struct ById(Id);
+
+    impl PartialEq for ById {
+        fn eq(&self, other: &Self) -> bool {
+            (self.0).0 == (other.0).0
+        }
+    }
+
+    impl Hash for ById {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.0.hash(state);
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, `Hash` is not consistent with `PartialEq`: `a == b` but their hashes differ
+    a: `ById(Id(1, 1))` (at index 0),
+    b: `ById(Id(1, 2))` (at index 2),
+    hash(a): `0xaf63bc4c8601b62c`,
+    hash(b): `0xaf63bf4c8601bb45`"#
+    )]
+    fn default_panic_message() {
+        assert_hash_consistent!([ById(Id(1, 1)), ById(Id(2, 1)), ById(Id(1, 2))]);
+    }
+
+    #[test]
+    #[should_panic(expected = "hash(b): `0xaf63bf4c8601bb45`: checking ById")]
+    fn custom_panic_message() {
+        assert_hash_consistent!([ById(Id(1, 1)), ById(Id(1, 2))], "checking {}", "ById");
+    }
+}
@@ -0,0 +1,199 @@
+/// Asserts that expression returns [`Err(E)`] variant, whose [`io::Error`] is of the given [`ErrorKind`].
+///
+/// This macro is available for Rust 1.30+, with the `std` feature enabled.
+///
+/// See [`assert_would_block!`], [`assert_interrupted!`] and [`assert_timed_out!`]
+/// for the kinds that are checked most often.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_io_err_kind!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// use std::fs::File;
+/// use std::io::ErrorKind;
+///
+/// # fn main() {
+/// assert_io_err_kind!(File::open("/does/not/exist"), ErrorKind::NotFound);
+///
+/// // With custom messages
+/// assert_io_err_kind!(File::open("/does/not/exist"), ErrorKind::NotFound, "expecting {} to be missing", "the file");
+/// # }
+/// ```
+///
+/// Value of `io::Error` type from `Err(io::Error)` will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::io::{self, ErrorKind};
+/// # fn main() {
+/// let res: io::Result<()> = Err(io::Error::new(ErrorKind::NotFound, "no such user"));
+///
+/// let error = assert_io_err_kind!(res, ErrorKind::NotFound);
+/// assert_eq!(error.to_string(), "no such user");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # use std::io::{self, ErrorKind};
+/// # fn main() {
+/// let res: io::Result<()> = Err(io::Error::new(ErrorKind::PermissionDenied, "access denied"));
+///
+/// assert_io_err_kind!(res, ErrorKind::NotFound);  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+/// [`ErrorKind`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html
+/// [`assert_would_block!`]: ./macro.assert_would_block.html
+/// [`assert_interrupted!`]: ./macro.assert_interrupted.html
+/// [`assert_timed_out!`]: ./macro.assert_timed_out.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_io_err_kind!`]: ./macro.debug_assert_io_err_kind.html
+#[macro_export]
+macro_rules! assert_io_err_kind {
+    ($cond:expr, $kind:expr,) => {
+        $crate::assert_io_err_kind!($cond, $kind)
+    };
+    ($cond:expr, $kind:expr) => {
+        match ($cond, &$kind) {
+            (Ok(t), _) => {
+                panic!("assertion failed, expected Err(..), got Ok({:?})", $crate::__debug_value!(t));
+            },
+            (Err(e), kind) => {
+                if e.kind() != *kind {
+                    panic!(r#"assertion failed, expected error of kind `{:?}`, got `{:?}`
+    error: `{}`"#, kind, e.kind(), e);
+                }
+                e
+            }
+        }
+    };
+    ($cond:expr, $kind:expr, $($arg:tt)+) => {
+        match ($cond, &$kind) {
+            (Ok(t), _) => {
+                panic!("assertion failed, expected Err(..), got Ok({:?}): {}", $crate::__debug_value!(t), format_args!($($arg)+));
+            },
+            (Err(e), kind) => {
+                if e.kind() != *kind {
+                    panic!(r#"assertion failed, expected error of kind `{:?}`, got `{:?}`
+    error: `{}`: {}"#, kind, e.kind(), e, format_args!($($arg)+));
+                }
+                e
+            }
+        }
+    };
+}
+
+/// Asserts that expression returns [`Err(E)`] variant, whose [`io::Error`] is of the given [`ErrorKind`]
+/// in runtime.
+///
+/// This macro is available for Rust 1.30+, with the `std` feature enabled.
+///
+/// Like [`assert_io_err_kind!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+/// [`ErrorKind`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_io_err_kind!`]: ./macro.assert_io_err_kind.html
+#[macro_export]
+macro_rules! debug_assert_io_err_kind {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_io_err_kind!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use std::cell::Cell;
+    use std::io::{self, ErrorKind};
+    use std::panic::{self, AssertUnwindSafe};
+
+    fn error(kind: ErrorKind) -> io::Result<()> {
+        Err(io::Error::new(kind, "operation failed"))
+    }
+
+    #[test]
+    fn returns_error() {
+        let error = assert_io_err_kind!(error(ErrorKind::NotFound), ErrorKind::NotFound,);
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn evaluates_kind_once() {
+        let evaluated = Cell::new(0);
+        let kind = || {
+            evaluated.set(evaluated.get() + 1);
+            ErrorKind::NotFound
+        };
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            assert_io_err_kind!(error(ErrorKind::PermissionDenied), kind())
+        }));
+        assert!(result.is_err());
+        assert_eq!(evaluated.get(), 1);
+    }
+
+    // The shorthands are declared after this module, so they are only in scope by path.
+    newer_syntax! {
+        #[test]
+        fn shorthands() {
+            assert_eq!(
+                crate::assert_would_block!(error(ErrorKind::WouldBlock)).kind(),
+                ErrorKind::WouldBlock
+            );
+            assert_eq!(
+                crate::assert_interrupted!(error(ErrorKind::Interrupted),).kind(),
+                ErrorKind::Interrupted
+            );
+            assert_eq!(
+                crate::assert_timed_out!(error(ErrorKind::TimedOut), "checking {}", "timeouts").kind(),
+                ErrorKind::TimedOut
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expected error of kind `NotFound`, got `PermissionDenied`
+    error: `operation failed`"#
+    )]
+    fn default_panic_message() {
+        let _ = assert_io_err_kind!(error(ErrorKind::PermissionDenied), ErrorKind::NotFound);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Err(..), got Ok(1): checking users")]
+    fn custom_panic_message() {
+        let res: io::Result<u8> = Ok(1);
+        let _ = assert_io_err_kind!(res, ErrorKind::NotFound, "checking {}", "users");
+    }
+
+    newer_syntax! {
+        #[test]
+        #[should_panic(
+            expected = r#"assertion failed, expected error of kind `WouldBlock`, got `Interrupted`
+    error: `operation failed`: checking would_block"#
+        )]
+        fn shorthand_panic_message() {
+            let _ =
+                crate::assert_would_block!(error(ErrorKind::Interrupted), "checking {}", "would_block");
+        }
+    }
+}
@@ -12,6 +12,20 @@
 /// This macro has a second form, where a custom panic message can be provided
 /// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
 ///
+/// ## Differences
+///
+/// From Rust 1.31 on, for operands of the same built-in numeric type, or any type
+/// implementing [`Delta`], the panic message also includes the difference `left - right`
+/// and the difference relative to `right`.
+///
+/// ## Unordered values
+///
+/// If the comparison fails because [`PartialOrd::partial_cmp`] returns `None` for the operands
+/// (e.g. one of them is `NaN`), the panic message states that the values are not comparable.
+///
+/// See [`assert_le_strict!`] to fail on unordered values even if a custom `PartialOrd`
+/// implementation reports them as `left <= right`.
+///
 /// ## Examples
 ///
 /// ```rust
@@ -34,8 +48,52 @@
 /// # }
 /// ```
 ///
+/// [`Delta`]: ./trait.Delta.html
+/// [`assert_le_strict!`]: ./macro.assert_le_strict.html
+/// [`PartialOrd::partial_cmp`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#tymethod.partial_cmp
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_le!`]: ./macro.debug_assert_le.html
+#[cfg(rustc_1_30)]
+#[macro_export]
+macro_rules! assert_le {
+    ($left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val <= *right_val) {
+                    if $crate::__private::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        panic!(r#"assertion failed: `(left <= right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val))
+                    }
+                    panic!(r#"assertion failed: `(left <= right)`
+    left: `{:?}`,
+    right: `{:?}`{}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), $crate::__delta_message!(*left_val, *right_val))
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr,) => {
+        $crate::assert_le!($left, $right);
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val <= *right_val) {
+                    if $crate::__private::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        panic!(r#"assertion failed: `(left <= right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`: {}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), format_args!($($arg)+))
+                    }
+                    panic!(r#"assertion failed: `(left <= right)`
+    left: `{:?}`,
+    right: `{:?}`{}: {}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), $crate::__delta_message!(*left_val, *right_val), format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(rustc_1_30))]
 #[macro_export]
 macro_rules! assert_le {
     ($left:expr, $right:expr) => {
@@ -45,6 +103,11 @@ macro_rules! assert_le {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
+                    if $crate::__private::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        panic!(r#"assertion failed: `(left <= right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`"#, &*left_val, &*right_val)
+                    }
                     panic!(r#"assertion failed: `(left <= right)`
     left: `{:?}`,
     right: `{:?}`"#, &*left_val, &*right_val)
@@ -62,6 +125,11 @@ macro_rules! assert_le {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
+                    if $crate::__private::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        panic!(r#"assertion failed: `(left <= right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`: {}"#, &*left_val, &*right_val, format_args!($($arg)+))
+                    }
                     panic!(r#"assertion failed: `(left <= right)`
     left: `{:?}`,
     right: `{:?}`: {}"#, &*left_val, &*right_val, format_args!($($arg)+))
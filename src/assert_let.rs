@@ -0,0 +1,199 @@
+/// Asserts that expression matches the given pattern, binding its variables in the current scope.
+///
+/// This macro is available for Rust 1.65+.
+///
+/// It works like a `let`-`else` statement, except it panics if there is no match:
+/// `assert_let!(Some(x) = maybe);` introduces `x` in the current scope,
+/// just like `let Some(x) = maybe else { panic!() };` would.
+///
+/// The expression is evaluated once and matched in place, so `ref` bindings borrow from it.
+/// As the expression cannot be printed once the pattern fails to match, the panic message
+/// shows it as written, along with the pattern.
+/// Like in `let` statements, alternatives need to be wrapped in parentheses,
+/// e.g. `assert_let!((Ok(x) | Err(x)) = result);`.
+///
+/// ## Guards
+///
+/// A guard can be given after the expression with `assert_let!(pattern = expression, if guard)`.
+/// It is checked once the pattern matched, with its variables in scope.
+/// The panic message of a guard that does not hold includes the pattern and the guard.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_let!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// #[derive(Debug)]
+/// struct Config {
+///     host: &'static str,
+///     port: u16,
+///     verbose: bool,
+/// }
+///
+/// fn load() -> Option<Config> {
+///     Some(Config { host: "localhost", port: 8080, verbose: false })
+/// }
+///
+/// # fn main() {
+/// assert_let!(Some(Config { host, port, .. }) = load());
+/// assert_eq!(host, "localhost");
+/// assert_eq!(port, 8080);
+///
+/// // With guards
+/// assert_let!(Some(Config { port, .. }) = load(), if port > 1024);
+///
+/// // With custom messages
+/// assert_let!(Some(config) = load(), if !config.verbose, "expecting quiet {}", "config");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let maybe: Option<i32> = None;
+/// assert_let!(Some(x) = maybe);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_let!`]: ./macro.debug_assert_let.html
+#[macro_export]
+macro_rules! assert_let {
+    (@let [$pattern:pat], $expression:expr, [$($arg:tt)*]) => {
+        let $pattern = ($expression) else {
+            $crate::assert_let!(@panic [r#"assertion failed, expression does not match any of the given variants.
+    expression: {}
+    variants: {}"#, stringify!($expression), stringify!($pattern)] [$($arg)*])
+        };
+    };
+    (@let [$pattern:pat], $expression:expr, if $guard:expr, [$($arg:tt)*]) => {
+        $crate::assert_let!(@let [$pattern], $expression, [$($arg)*]);
+        if !$guard {
+            $crate::assert_let!(@panic [r#"assertion failed, expression matches one of the given variants, but the guard does not hold.
+    variant: {}
+    guard: {}"#, stringify!($pattern), stringify!($guard)] [$($arg)*])
+        }
+    };
+    (@panic [$format:expr, $($value:expr),+] []) => {
+        panic!($format, $($value),+)
+    };
+    (@panic [$format:expr, $($value:expr),+] [$($arg:tt)+]) => {
+        panic!(concat!($format, ": {}"), $($value),+, format_args!($($arg)+))
+    };
+
+    ($pattern:pat = $expression:expr, if $guard:expr) => {
+        $crate::assert_let!(@let [$pattern], $expression, if $guard, []);
+    };
+    ($pattern:pat = $expression:expr, if $guard:expr,) => {
+        $crate::assert_let!(@let [$pattern], $expression, if $guard, []);
+    };
+    ($pattern:pat = $expression:expr, if $guard:expr, $($arg:tt)+) => {
+        $crate::assert_let!(@let [$pattern], $expression, if $guard, [$($arg)+]);
+    };
+    ($pattern:pat = $expression:expr) => {
+        $crate::assert_let!(@let [$pattern], $expression, []);
+    };
+    ($pattern:pat = $expression:expr,) => {
+        $crate::assert_let!(@let [$pattern], $expression, []);
+    };
+    ($pattern:pat = $expression:expr, $($arg:tt)+) => {
+        $crate::assert_let!(@let [$pattern], $expression, [$($arg)+]);
+    };
+}
+
+/// Asserts that expression matches the given pattern in runtime.
+///
+/// This macro is available for Rust 1.65+.
+///
+/// Like [`assert_let!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// As the pattern is only matched in debug builds, its variables are not bound
+/// in the current scope, unlike with [`assert_let!`].
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_let!`]: ./macro.assert_let.html
+#[macro_export]
+macro_rules! debug_assert_let {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_let!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    struct Config {
+        host: &'static str,
+        port: u16,
+    }
+
+    #[test]
+    fn binds_variables() {
+        assert_let!(
+            Some(Config { host, port }) = Some(Config {
+                host: "localhost",
+                port: 80
+            })
+        );
+        assert_eq!((host, port), ("localhost", 80));
+
+        let configs = [Config { host: "a", port: 1 }, Config { host: "b", port: 2 }];
+        assert_let!([first, .., last] = &configs[..], if first.port < last.port);
+        assert_eq!((first.host, last.host), ("a", "b"));
+
+        assert_let!(([port] | [_, port]) = &[80, 443][..], "checking {}", "port");
+        assert_eq!(*port, 443);
+    }
+
+    #[test]
+    fn borrows_places() {
+        let config = Some(Config {
+            host: "localhost",
+            port: 80,
+        });
+        let configs = [&config];
+        assert_let!(Some(ref first) = *configs[0], if first.port == 80);
+        assert_eq!(first.host, "localhost");
+        assert_let!(Some(ref config) = config);
+        assert_eq!(config.port, 80);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression does not match any of the given variants.
+    expression: Some(Config { host: "localhost", port: 80 })
+    variants: None"#
+    )]
+    fn default_panic_message() {
+        assert_let!(
+            None = Some(Config {
+                host: "localhost",
+                port: 80
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression matches one of the given variants, but the guard does not hold.
+    variant: Some(Config { port, .. })
+    guard: port > 1024: checking 80"#
+    )]
+    fn guard_panic_message() {
+        assert_let!(Some(Config { port, .. }) = Some(Config { host: "localhost", port: 80 }), if port > 1024, "checking {}", 80);
+    }
+}
@@ -12,6 +12,20 @@
 /// This macro has a second form, where a custom panic message can be provided
 /// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
 ///
+/// ## Differences
+///
+/// From Rust 1.31 on, for operands of the same built-in numeric type, or any type
+/// implementing [`Delta`], the panic message also includes the difference `left - right`
+/// and the difference relative to `right`.
+///
+/// ## Unordered values
+///
+/// If the comparison fails because [`PartialOrd::partial_cmp`] returns `None` for the operands
+/// (e.g. one of them is `NaN`), the panic message states that the values are not comparable.
+///
+/// See [`assert_lt_strict!`] to fail on unordered values even if a custom `PartialOrd`
+/// implementation reports them as `left < right`.
+///
 /// ## Examples
 ///
 /// ```rust
@@ -35,8 +49,52 @@
 /// # }
 /// ```
 ///
+/// [`Delta`]: ./trait.Delta.html
+/// [`assert_lt_strict!`]: ./macro.assert_lt_strict.html
+/// [`PartialOrd::partial_cmp`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#tymethod.partial_cmp
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_lt!`]: ./macro.debug_assert_lt.html
+#[cfg(rustc_1_30)]
+#[macro_export]
+macro_rules! assert_lt {
+    ($left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val < *right_val) {
+                    if $crate::__private::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        panic!(r#"assertion failed: `(left < right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val))
+                    }
+                    panic!(r#"assertion failed: `(left < right)`
+    left: `{:?}`,
+    right: `{:?}`{}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), $crate::__delta_message!(*left_val, *right_val))
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr,) => {
+        $crate::assert_lt!($left, $right);
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !(*left_val < *right_val) {
+                    if $crate::__private::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        panic!(r#"assertion failed: `(left < right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`: {}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), format_args!($($arg)+))
+                    }
+                    panic!(r#"assertion failed: `(left < right)`
+    left: `{:?}`,
+    right: `{:?}`{}: {}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), $crate::__delta_message!(*left_val, *right_val), format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(rustc_1_30))]
 #[macro_export]
 macro_rules! assert_lt {
     ($left:expr, $right:expr) => {
@@ -46,6 +104,11 @@ macro_rules! assert_lt {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
+                    if $crate::__private::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        panic!(r#"assertion failed: `(left < right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`"#, &*left_val, &*right_val)
+                    }
                     panic!(r#"assertion failed: `(left < right)`
     left: `{:?}`,
     right: `{:?}`"#, &*left_val, &*right_val)
@@ -63,6 +126,11 @@ macro_rules! assert_lt {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down.
+                    if $crate::__private::PartialOrd::partial_cmp(&*left_val, &*right_val).is_none() {
+                        panic!(r#"assertion failed: `(left < right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`: {}"#, &*left_val, &*right_val, format_args!($($arg)+))
+                    }
                     panic!(r#"assertion failed: `(left < right)`
     left: `{:?}`,
     right: `{:?}`: {}"#, &*left_val, &*right_val, format_args!($($arg)+))
@@ -88,3 +156,93 @@ macro_rules! assert_lt {
 macro_rules! debug_assert_lt {
     ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_lt!($($arg)*); })
 }
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::cmp::Ordering;
+
+    #[derive(Debug, PartialEq)]
+    struct AlwaysLess;
+
+    impl PartialOrd for AlwaysLess {
+        fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+            None
+        }
+
+        fn lt(&self, _: &Self) -> bool {
+            true
+        }
+    }
+
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Version(u32);
+
+    #[test]
+    #[cfg_attr(
+        not(rustc_1_31),
+        ignore = "differences are only reported in rustc 1.31.0 or later"
+    )]
+    #[should_panic(expected = r#"assertion failed: `(left < right)`
+    left: `120`,
+    right: `100`,
+    difference: `20`,
+    relative difference: `+20.00%`"#)]
+    fn default_panic_message() {
+        assert_lt!(120u8, 100);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rustc_1_31),
+        ignore = "differences are only reported in rustc 1.31.0 or later"
+    )]
+    #[should_panic(expected = r#"assertion failed: `(left < right)`
+    left: `127`,
+    right: `-128`,
+    difference: `255`,
+    relative difference: `+199.22%`: checking i8 bounds"#)]
+    fn custom_panic_message() {
+        assert_lt!(127i8, -128i8, "checking i8 bounds");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left < right)`
+    left: `Version(2)`,
+    right: `Version(1)`: checking versions"#)]
+    fn no_difference_without_delta() {
+        assert_lt!(Version(2), Version(1), "checking versions");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(left < right)`, values are not comparable
+    left: `NaN`,
+    right: `1.5`: checking NaN"#
+    )]
+    fn unordered_panic_message() {
+        let nan = "NaN".parse::<f64>().unwrap();
+        assert_lt!(nan, 1.5, "checking {:?}", nan);
+    }
+
+    #[test]
+    fn uses_lt() {
+        assert_lt!(AlwaysLess, AlwaysLess);
+    }
+
+    #[test]
+    #[cfg(rustc_1_31)]
+    #[cfg_attr(
+        not(rustc_1_38),
+        ignore = "type names are only available in rustc 1.38.0 or later"
+    )]
+    #[should_panic(expected = r#"assertion failed: `(left < right)`
+    left: `<claims::assert_lt::tests::does_not_require_debug::Priority: no Debug impl>`,
+    right: `<claims::assert_lt::tests::does_not_require_debug::Priority: no Debug impl>`"#)]
+    fn does_not_require_debug() {
+        #[derive(PartialEq, PartialOrd)]
+        struct Priority(u8);
+
+        assert_lt!(Priority(2), Priority(1));
+    }
+}
@@ -0,0 +1,139 @@
+/// Asserts that first expression is less than the second, failing on unordered values.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Unlike [`assert_lt!`], this macro checks [`PartialOrd::partial_cmp`] explicitly,
+/// and fails when it returns `None` even if a custom `PartialOrd` implementation
+/// reports the values as `left < right`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_lt_strict!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_lt_strict!(1, 2);
+///
+/// // With custom messages
+/// assert_lt_strict!(1, 2, "Expecting that {} is less than {}", 1, 2);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let nan = "NaN".parse::<f64>().unwrap();
+///
+/// assert_lt_strict!(nan, 1.0);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_lt!`]: ./macro.assert_lt.html
+/// [`PartialOrd::partial_cmp`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#tymethod.partial_cmp
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_lt_strict!`]: ./macro.debug_assert_lt_strict.html
+#[macro_export]
+macro_rules! assert_lt_strict {
+    ($left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if $crate::__private::PartialOrd::partial_cmp(left_val, right_val).is_none() {
+                    panic!(r#"assertion failed: `(left < right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val))
+                }
+                $crate::assert_lt!(*left_val, *right_val);
+            }
+        }
+    };
+    ($left:expr, $right:expr,) => {
+        $crate::assert_lt_strict!($left, $right);
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if $crate::__private::PartialOrd::partial_cmp(left_val, right_val).is_none() {
+                    panic!(r#"assertion failed: `(left < right)`, values are not comparable
+    left: `{:?}`,
+    right: `{:?}`: {}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), format_args!($($arg)+))
+                }
+                $crate::assert_lt!(*left_val, *right_val, $($arg)+);
+            }
+        }
+    };
+}
+
+/// Asserts that first expression is less than the second, failing on unordered values in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_lt_strict!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_lt_strict!`]: ./macro.assert_lt_strict.html
+#[macro_export]
+macro_rules! debug_assert_lt_strict {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_lt_strict!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::cmp::Ordering;
+
+    #[derive(Debug, PartialEq)]
+    struct AlwaysLess;
+
+    impl PartialOrd for AlwaysLess {
+        fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+            None
+        }
+
+        fn lt(&self, _: &Self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(left < right)`, values are not comparable
+    left: `AlwaysLess`,
+    right: `AlwaysLess`"#
+    )]
+    fn default_panic_message() {
+        assert_lt_strict!(AlwaysLess, AlwaysLess);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(left < right)`, values are not comparable
+    left: `AlwaysLess`,
+    right: `AlwaysLess`: checking AlwaysLess"#
+    )]
+    fn custom_panic_message() {
+        assert_lt_strict!(AlwaysLess, AlwaysLess, "checking {:?}", AlwaysLess);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left < right)`
+    left: `2`,
+    right: `1`"#)]
+    fn ordered_values() {
+        assert_lt_strict!(2, 1);
+    }
+}
@@ -5,6 +5,27 @@
 /// It works exactly as [`std::matches!`] macro,
 /// except it panics if there is no match.
 ///
+/// ## Guards
+///
+/// From Rust 1.30 on, when the expression matches one of the variants but the guard
+/// does not hold, the panic message says so, naming the variant that matched and the
+/// guard, instead of reporting that the expression does not match.
+///
+/// ## Mismatches
+///
+/// From Rust 1.30 on, when a single variant is given, the panic message also names the
+/// innermost sub-pattern that does not match, along with its path in the expression,
+/// e.g. `mismatch: .0.status does not match 200`. Sub-patterns that might be bindings
+/// or constants, slice patterns and the fields after `..` are only named when
+/// everything else matches.
+///
+/// ## Bindings
+///
+/// The pattern can be followed by `=> expression`, which is evaluated with the bindings
+/// of the pattern in scope and returned by the macro, just like a `match` arm.
+/// This way values can be moved (or, when matching on a reference, borrowed)
+/// out of the matched expression without matching it a second time.
+///
 /// ## Uses
 ///
 /// Assertions are always checked in both debug and release builds, and cannot be disabled.
@@ -25,6 +46,12 @@
 ///
 /// // With custom messages
 /// assert_matches!(foo, 'A'..='Z' | 'a'..='z', "expecting it to be letter: {}", foo);
+///
+/// // Returning the bindings
+/// let pair = (Some("user"), 42);
+/// let (user, timestamp) = assert_matches!(pair, (Some(user), timestamp) if timestamp > 0 => (user, timestamp));
+/// assert_eq!(user, "user");
+/// assert_eq!(timestamp, 42);
 /// # }
 /// ```
 ///
@@ -39,6 +66,351 @@
 /// [`std::matches!`]: https://doc.rust-lang.org/stable/std/macro.matches.html
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_matches!`]: ./macro.debug_assert_matches.html
+#[cfg(rustc_1_30)]
+#[macro_export]
+macro_rules! assert_matches {
+    // Matches every variant in an arm of its own, which is equivalent to matching them in a single
+    // arm, as the guard is checked again for every variant that matches. This way the first
+    // variant that matched with a guard that does not hold is recorded, without matching the value
+    // a second time, which would either move it or leave the bindings of the pattern unused.
+    (@guarded $expression:expr, [$( $pattern:pat )|+ if $guard:expr], [$result:expr], |$other:ident, $variant:ident| $otherwise:expr) => {{
+        let $variant = $crate::__private::Cell::new(None);
+        match $expression {
+            $(
+                $pattern if $guard || {
+                    if $variant.get().is_none() {
+                        $variant.set(Some(stringify!($pattern)));
+                    }
+                    false
+                } => $result,
+            )+
+            $other => {
+                let $variant = $variant.get();
+                $otherwise
+            }
+        }
+    }};
+    // Matches the value unwrapped from the `$kind` variant by the `assert_*_matches!` macros.
+    (@unwrapped $kind:expr, $value:ident, [$( $pattern:pat )|+], [$result:expr], [$($arg:tt)*]) => {
+        match $value {
+            $( $pattern )|+ => $result,
+            other => {
+                $crate::assert_matches!(@panic [concat!("assertion failed, ", $kind, r#" value does not match any of the given variants.
+    value: {:?}
+    variants: {}"#), $crate::__debug_value!(other), stringify!($($pattern) |+)] [$($arg)*])
+            }
+        }
+    };
+    (@unwrapped $kind:expr, $value:ident, [$( $pattern:pat )|+ if $guard:expr], [$result:expr], [$($arg:tt)*]) => {
+        $crate::assert_matches!(@guarded $value, [$($pattern)|+ if $guard], [$result], |other, variant| {
+            if let Some(variant) = variant {
+                $crate::assert_matches!(@panic [concat!("assertion failed, ", $kind, r#" value matches one of the given variants, but the guard does not hold.
+    value: {:?}
+    variant: {}
+    guard: {}"#), $crate::__debug_value!(other), variant, stringify!($guard)] [$($arg)*]);
+            }
+            $crate::assert_matches!(@panic [concat!("assertion failed, ", $kind, r#" value does not match any of the given variants.
+    value: {:?}
+    variants: {}"#), $crate::__debug_value!(other), stringify!($($pattern) |+ if $guard)] [$($arg)*])
+        })
+    };
+    (@panic [$format:expr, $($value:expr),+] []) => {
+        panic!($format, $($value),+)
+    };
+    (@panic [$format:expr, $($value:expr),+] [$($arg:tt)+]) => {
+        panic!(concat!($format, ": {}"), $($value),+, format_args!($($arg)+))
+    };
+    (@panic [$format:expr] []) => {
+        panic!($format)
+    };
+    (@panic [$format:expr] [$($arg:tt)+]) => {
+        panic!(concat!($format, ": {}"), format_args!($($arg)+))
+    };
+    // Splits the variants at the top-level `|`, keeping their tokens, which are walked to find
+    // the sub-pattern that does not match, and then parses the rest of the arguments.
+    (@split $expression:expr, [$($variants:tt)*] [] | $($rest:tt)*) => {
+        $crate::assert_matches!(@split $expression, [$($variants)*] [] $($rest)*)
+    };
+    (@split $expression:expr, [$($variants:tt)*] [$($pattern:tt)+] | $($rest:tt)*) => {
+        $crate::assert_matches!(@split $expression, [$($variants)* [$($pattern)+]] [] $($rest)*)
+    };
+    (@split $expression:expr, [$($variants:tt)*] [$($pattern:tt)+] if $($rest:tt)+) => {
+        $crate::assert_matches!(@arms $expression, [$($variants)* [$($pattern)+]] if $($rest)+)
+    };
+    (@split $expression:expr, [$($variants:tt)*] [$($pattern:tt)+] => $($rest:tt)+) => {
+        $crate::assert_matches!(@arms $expression, [$($variants)* [$($pattern)+]] => $($rest)+)
+    };
+    (@split $expression:expr, [$($variants:tt)*] [$($pattern:tt)+] , $($rest:tt)+) => {
+        $crate::assert_matches!(@arms $expression, [$($variants)* [$($pattern)+]], $($rest)+)
+    };
+    (@split $expression:expr, [$($variants:tt)*] [$($pattern:tt)+]) => {
+        $crate::assert_matches!(@arms $expression, [$($variants)* [$($pattern)+]])
+    };
+    (@split $expression:expr, [$($variants:tt)*] [$($pattern:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_matches!(@split $expression, [$($variants)*] [$($pattern)* $next] $($rest)*)
+    };
+    (@arms $expression:expr, $variants:tt if $guard:expr => $result:expr, $($arg:tt)+) => {
+        $crate::assert_matches!(@check $expression, $variants, [$guard], [$result], [$($arg)+])
+    };
+    (@arms $expression:expr, $variants:tt if $guard:expr => $result:expr) => {
+        $crate::assert_matches!(@check $expression, $variants, [$guard], [$result], [])
+    };
+    (@arms $expression:expr, $variants:tt if $guard:expr, $($arg:tt)+) => {
+        $crate::assert_matches!(@check $expression, $variants, [$guard], [{}], [$($arg)+])
+    };
+    (@arms $expression:expr, $variants:tt if $guard:expr) => {
+        $crate::assert_matches!(@check $expression, $variants, [$guard], [{}], [])
+    };
+    (@arms $expression:expr, $variants:tt => $result:expr, $($arg:tt)+) => {
+        $crate::assert_matches!(@check $expression, $variants, [], [$result], [$($arg)+])
+    };
+    (@arms $expression:expr, $variants:tt => $result:expr) => {
+        $crate::assert_matches!(@check $expression, $variants, [], [$result], [])
+    };
+    (@arms $expression:expr, $variants:tt, $($arg:tt)+) => {
+        $crate::assert_matches!(@check $expression, $variants, [], [{}], [$($arg)+])
+    };
+    (@arms $expression:expr, $variants:tt) => {
+        $crate::assert_matches!(@check $expression, $variants, [], [{}], [])
+    };
+    (@check $expression:expr, [$([$($pattern:tt)+])+], [], [$result:expr], [$($arg:tt)*]) => {
+        match $expression {
+            $( $($pattern)+ )|+ => $result,
+            other => {
+                $crate::assert_matches!(@mismatch other, [$([$($pattern)+])+], $crate::assert_matches!(@stringify [$($($pattern)+)|+]), [$($arg)*])
+            }
+        }
+    };
+    (@check $expression:expr, [$([$($pattern:tt)+])+], [$guard:expr], [$result:expr], [$($arg:tt)*]) => {
+        $crate::assert_matches!(@guarded $expression, [$($($pattern)+)|+ if $guard], [$result], |other, variant| {
+            if let Some(variant) = variant {
+                $crate::assert_matches!(@panic [r#"assertion failed, expression matches one of the given variants, but the guard does not hold.
+    expression: {:?}
+    variant: {}
+    guard: {}"#, $crate::__debug_value!(other), variant, stringify!($guard)] [$($arg)*]);
+            }
+            $crate::assert_matches!(@mismatch other, [$([$($pattern)+])+], $crate::assert_matches!(@stringify [$($($pattern)+)|+] if $guard), [$($arg)*])
+        })
+    };
+    (@mismatch $other:ident, $variants:tt, $stringified:expr, [$($arg:tt)*]) => {{
+        if let Some((path, sub_pattern)) = $crate::assert_matches!(@locate &$other, $variants).mismatch() {
+            $crate::assert_matches!(@panic [r#"assertion failed, expression does not match any of the given variants.
+    expression: {:?}
+    variants: {}
+    mismatch: {} does not match {}"#, $crate::__debug_value!($other), $stringified, path, sub_pattern] [$($arg)*]);
+        }
+        $crate::assert_matches!(@panic [r#"assertion failed, expression does not match any of the given variants.
+    expression: {:?}
+    variants: {}"#, $crate::__debug_value!($other), $stringified] [$($arg)*])
+    }};
+    // Stringifies the variants as patterns, which prints them the same way on every compiler.
+    (@stringify [$($pattern:pat)|+]) => {
+        stringify!($($pattern) |+)
+    };
+    (@stringify [$($pattern:pat)|+] if $guard:expr) => {
+        stringify!($($pattern) |+ if $guard)
+    };
+    // Finds the innermost sub-pattern of a single variant that does not match, walking the tuple,
+    // tuple struct and struct patterns. The fields are bound by reference, and compared against
+    // the sub-patterns that cannot contain bindings, so that nothing is moved out of the value.
+    (@locate $value:expr, [[$($pattern:tt)+]]) => {
+        $crate::assert_matches!(@node $value, [], $($pattern)+)
+    };
+    (@locate $value:expr, $variants:tt) => {
+        $crate::__private::SubPattern::Ambiguous
+    };
+    (@node $value:expr, $path:tt, _) => {{
+        let _ = $value;
+        $crate::__private::SubPattern::Matched
+    }};
+    (@node $value:expr, $path:tt, ref mut $binding:ident @ $($pattern:tt)+) => {
+        $crate::assert_matches!(@node $value, $path, $($pattern)+)
+    };
+    (@node $value:expr, $path:tt, ref $binding:ident @ $($pattern:tt)+) => {
+        $crate::assert_matches!(@node $value, $path, $($pattern)+)
+    };
+    (@node $value:expr, $path:tt, mut $binding:ident @ $($pattern:tt)+) => {
+        $crate::assert_matches!(@node $value, $path, $($pattern)+)
+    };
+    (@node $value:expr, $path:tt, $binding:ident @ $($pattern:tt)+) => {
+        $crate::assert_matches!(@node $value, $path, $($pattern)+)
+    };
+    (@node $value:expr, $path:tt, ref mut $binding:ident) => {
+        $crate::assert_matches!(@node $value, $path, _)
+    };
+    (@node $value:expr, $path:tt, ref $binding:ident) => {
+        $crate::assert_matches!(@node $value, $path, _)
+    };
+    (@node $value:expr, $path:tt, mut $binding:ident) => {
+        $crate::assert_matches!(@node $value, $path, _)
+    };
+    (@node $value:expr, $path:tt, true) => {
+        $crate::assert_matches!(@classify $value, $path, [true] [] true)
+    };
+    (@node $value:expr, $path:tt, false) => {
+        $crate::assert_matches!(@classify $value, $path, [false] [] false)
+    };
+    // Either a binding, which always matches, or a constant.
+    (@node $value:expr, $path:tt, $binding:ident) => {{
+        let _ = $value;
+        $crate::__private::SubPattern::Unchecked($crate::assert_matches!(@path $path), stringify!($binding))
+    }};
+    (@node $value:expr, $path:tt, && $($pattern:tt)+) => {
+        $crate::assert_matches!(@node &**$value, $path, & $($pattern)+)
+    };
+    (@node $value:expr, $path:tt, &mut $($pattern:tt)+) => {
+        $crate::assert_matches!(@node &**$value, $path, $($pattern)+)
+    };
+    (@node $value:expr, $path:tt, & $($pattern:tt)+) => {
+        $crate::assert_matches!(@node &**$value, $path, $($pattern)+)
+    };
+    (@node $value:expr, $path:tt, ( $($fields:tt)* )) => {
+        $crate::assert_matches!(@fields $value, $path, [($($fields)*)] [], [] [] $($fields)*)
+    };
+    (@node $value:expr, $path:tt, $($pattern:tt)+) => {
+        $crate::assert_matches!(@classify $value, $path, [$($pattern)+] [] $($pattern)+)
+    };
+    // Finds the path of a tuple struct or struct pattern.
+    (@classify $value:expr, $path:tt, $node:tt [$($head:tt)+] ( $($fields:tt)* )) => {
+        $crate::assert_matches!(@fields $value, $path, $node [$($head)+], [] [] $($fields)*)
+    };
+    (@classify $value:expr, $path:tt, $node:tt [$($head:tt)+] { $($fields:tt)* }) => {
+        $crate::assert_matches!(@struct $value, $path, $node [$($head)+], [] [] $($fields)*)
+    };
+    (@classify $value:expr, $path:tt, $node:tt [$($head:tt)*] ! $($rest:tt)*) => {
+        $crate::assert_matches!(@unchecked $value, $path, $node)
+    };
+    (@classify $value:expr, $path:tt, $node:tt [$($head:tt)*] ( $($group:tt)* ) $($rest:tt)*) => {
+        $crate::assert_matches!(@unchecked $value, $path, $node)
+    };
+    (@classify $value:expr, $path:tt, $node:tt [$($head:tt)*] [ $($group:tt)* ] $($rest:tt)*) => {
+        $crate::assert_matches!(@unchecked $value, $path, $node)
+    };
+    (@classify $value:expr, $path:tt, $node:tt [$($head:tt)*] { $($group:tt)* } $($rest:tt)*) => {
+        $crate::assert_matches!(@unchecked $value, $path, $node)
+    };
+    (@classify $value:expr, $path:tt, $node:tt [$($head:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::assert_matches!(@classify $value, $path, $node [$($head)* $next] $($rest)+)
+    };
+    // Literals, ranges and paths to constants, which cannot contain bindings.
+    (@classify $value:expr, $path:tt, [$($node:tt)+] [$($head:tt)*] $last:tt) => {
+        // The guard keeps the other arm reachable when the pattern is irrefutable.
+        match *$value {
+            $($node)+ if true => $crate::__private::SubPattern::Matched,
+            _ => $crate::__private::SubPattern::Mismatched($crate::assert_matches!(@path $path), $crate::assert_matches!(@stringify [$($node)+])),
+        }
+    };
+    (@unchecked $value:expr, $path:tt, [$($node:tt)+]) => {{
+        let _ = $value;
+        $crate::__private::SubPattern::Unchecked($crate::assert_matches!(@path $path), $crate::assert_matches!(@stringify [$($node)+]))
+    }};
+    // Joins the segments of a path, which is nested in the path of its parent.
+    (@path [] $($segments:tt)*) => {
+        concat!($($segments)* "")
+    };
+    (@path [$parent:tt $($segment:tt)+] $($segments:tt)*) => {
+        $crate::assert_matches!(@path $parent $($segment)+ $($segments)*)
+    };
+    // Splits the fields of a tuple or tuple struct pattern at the top-level commas.
+    (@fields $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] [$($field:tt)+] , $($rest:tt)*) => {
+        $crate::assert_matches!(@fields $value, $path, $node $head, [$($fields)* [$($field)+]] [] $($rest)*)
+    };
+    (@fields $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] [$($field:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_matches!(@fields $value, $path, $node $head, [$($fields)*] [$($field)* $next] $($rest)*)
+    };
+    // A single pattern in parentheses without a trailing comma is not a tuple.
+    (@fields $value:expr, $path:tt, $node:tt [], [] [$($field:tt)+]) => {
+        $crate::assert_matches!(@node $value, $path, $($field)+)
+    };
+    (@fields $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] [$($field:tt)+]) => {
+        $crate::assert_matches!(@positional $value, $path, $node $head, [] $($fields)* [$($field)+])
+    };
+    (@fields $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] []) => {
+        $crate::assert_matches!(@positional $value, $path, $node $head, [] $($fields)*)
+    };
+    // Only the fields before `..` have a known position.
+    (@positional $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] [..] $($rest:tt)+) => {
+        $crate::assert_matches!(@bind $value, $path, $node $head, [unchecked], [] [field0 field1 field2 field3 field4 field5 field6 field7] [".0" ".1" ".2" ".3" ".4" ".5" ".6" ".7"] $($fields)*)
+    };
+    (@positional $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] [..]) => {
+        $crate::assert_matches!(@positional $value, $path, $node $head, [$($fields)*])
+    };
+    (@positional $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] $field:tt $($rest:tt)*) => {
+        $crate::assert_matches!(@positional $value, $path, $node $head, [$($fields)* $field] $($rest)*)
+    };
+    (@positional $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*]) => {
+        $crate::assert_matches!(@bind $value, $path, $node $head, [], [] [field0 field1 field2 field3 field4 field5 field6 field7] [".0" ".1" ".2" ".3" ".4" ".5" ".6" ".7"] $($fields)*)
+    };
+    // Pairs the fields with the bindings for their values and their paths.
+    (@bind $value:expr, $path:tt, $node:tt $head:tt, $unchecked:tt, [$($bound:tt)*] [$binding:ident $($bindings:ident)*] [$index:tt $($indices:tt)*] $field:tt $($rest:tt)*) => {
+        $crate::assert_matches!(@bind $value, $path, $node $head, $unchecked, [$($bound)* ($binding $index $field)] [$($bindings)*] [$($indices)*] $($rest)*)
+    };
+    (@bind $value:expr, $path:tt, $node:tt $head:tt, $unchecked:tt, $bound:tt $bindings:tt $indices:tt) => {
+        $crate::assert_matches!(@tuple $value, $path, $node $head, $unchecked, $bound)
+    };
+    (@bind $value:expr, $path:tt, $node:tt $head:tt, $unchecked:tt, $bound:tt [] [] $($rest:tt)+) => {
+        $crate::assert_matches!(@unchecked $value, $path, $node)
+    };
+    (@tuple $value:expr, $path:tt, $node:tt [], [$($unchecked:ident)*], [$(($binding:ident $index:tt [$($field:tt)+]))*]) => {
+        match $value {
+            ($($binding,)* ..) => {
+                $crate::__private::SubPattern::Matched
+                    $(.and($crate::assert_matches!(@node $binding, [$path $index,], $($field)+)))*
+                    $(.and($crate::assert_matches!(@$unchecked $value, $path, $node)))*
+            }
+        }
+    };
+    (@tuple $value:expr, $path:tt, $node:tt [$($head:tt)+], [$($unchecked:ident)*], [$(($binding:ident $index:tt [$($field:tt)+]))*]) => {
+        // The guard keeps the other arm reachable for tuple structs, which have a single variant.
+        match $value {
+            $($head)+ ($($binding,)* ..) if true => {
+                $crate::__private::SubPattern::Matched
+                    $(.and($crate::assert_matches!(@node $binding, [$path $index,], $($field)+)))*
+                    $(.and($crate::assert_matches!(@$unchecked $value, $path, $node)))*
+            }
+            _ => $crate::__private::SubPattern::Mismatched($crate::assert_matches!(@path $path), $crate::assert_matches!(@stringify $node)),
+        }
+    };
+    // Splits the fields of a struct pattern at the top-level commas.
+    (@struct $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] [$($field:tt)+] , $($rest:tt)*) => {
+        $crate::assert_matches!(@struct $value, $path, $node $head, [$($fields)* [$($field)+]] [] $($rest)*)
+    };
+    (@struct $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] [$($field:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_matches!(@struct $value, $path, $node $head, [$($fields)*] [$($field)* $next] $($rest)*)
+    };
+    (@struct $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] [$($field:tt)+]) => {
+        $crate::assert_matches!(@named $value, $path, $node $head, [] [field0 field1 field2 field3 field4 field5 field6 field7] $($fields)* [$($field)+])
+    };
+    (@struct $value:expr, $path:tt, $node:tt $head:tt, [$($fields:tt)*] []) => {
+        $crate::assert_matches!(@named $value, $path, $node $head, [] [field0 field1 field2 field3 field4 field5 field6 field7] $($fields)*)
+    };
+    // Pairs the named fields with the bindings for their values, skipping the shorthands, which
+    // are bindings themselves.
+    (@named $value:expr, $path:tt, $node:tt $head:tt, [$($bound:tt)*] [$binding:ident $($bindings:ident)*] [$name:tt : $($field:tt)+] $($rest:tt)*) => {
+        $crate::assert_matches!(@named $value, $path, $node $head, [$($bound)* ($name $binding [$($field)+])] [$($bindings)*] $($rest)*)
+    };
+    (@named $value:expr, $path:tt, $node:tt $head:tt, $bound:tt [] [$name:tt : $($field:tt)+] $($rest:tt)*) => {
+        $crate::assert_matches!(@unchecked $value, $path, $node)
+    };
+    (@named $value:expr, $path:tt, $node:tt $head:tt, $bound:tt $bindings:tt [$($shorthand:tt)+] $($rest:tt)*) => {
+        $crate::assert_matches!(@named $value, $path, $node $head, $bound $bindings $($rest)*)
+    };
+    (@named $value:expr, $path:tt, $node:tt [$($head:tt)+], [$(($name:tt $binding:ident [$($field:tt)+]))*] $bindings:tt) => {
+        // The guard keeps the other arm reachable for structs, which have a single variant.
+        match $value {
+            $($head)+ { $($name: $binding,)* .. } if true => {
+                $crate::__private::SubPattern::Matched
+                    $(.and($crate::assert_matches!(@node $binding, [$path ".", stringify!($name),], $($field)+)))*
+            }
+            _ => $crate::__private::SubPattern::Mismatched($crate::assert_matches!(@path $path), $crate::assert_matches!(@stringify $node)),
+        }
+    };
+    ($expression:expr, $($rest:tt)+) => {
+        $crate::assert_matches!(@split $expression, [] [] $($rest)+)
+    };
+}
+
+#[cfg(not(rustc_1_30))]
 #[macro_export]
 macro_rules! assert_matches {
     ($expression:expr, $( $pattern:pat )|+) => {
@@ -81,6 +453,46 @@ macro_rules! assert_matches {
             }
         }
     };
+    ($expression:expr, $( $pattern:pat )|+ => $result:expr) => {
+        match $expression {
+            $( $pattern )|+ => $result,
+            other => {
+                panic!(r#"assertion failed, expression does not match any of the given variants.
+    expression: {:?}
+    variants: {}"#, other, stringify!($($pattern) |+));
+            }
+        }
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard: expr => $result:expr) => {
+        match $expression {
+            $( $pattern )|+ if $guard => $result,
+            other => {
+                panic!(r#"assertion failed, expression does not match any of the given variants.
+    expression: {:?}
+    variants: {}"#, other, stringify!($($pattern) |+ if $guard));
+            }
+        }
+    };
+    ($expression:expr, $( $pattern:pat )|+ => $result:expr, $($arg:tt)+) => {
+        match $expression {
+            $( $pattern )|+ => $result,
+            other => {
+                panic!(r#"assertion failed, expression does not match any of the given variants.
+    expression: {:?}
+    variants: {}: {}"#, other, stringify!($($pattern) |+), format_args!($($arg)+));
+            }
+        }
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard: expr => $result:expr, $($arg:tt)+) => {
+        match $expression {
+            $( $pattern )|+ if $guard => $result,
+            other => {
+                panic!(r#"assertion failed, expression does not match any of the given variants.
+    expression: {:?}
+    variants: {}: {}"#, other, stringify!($($pattern) |+ if $guard), format_args!($($arg)+));
+            }
+        }
+    };
 }
 
 /// Asserts that expression matches any of the given variants.
@@ -100,3 +512,150 @@ macro_rules! assert_matches {
 macro_rules! debug_assert_matches {
     ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_matches!($($arg)*); })
 }
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    enum Event {
+        Login { user: &'static str, ts: u64 },
+        Logout { ts: u64 },
+    }
+
+    #[derive(Debug)]
+    struct Response {
+        status: u16,
+        body: Option<&'static str>,
+    }
+
+    #[cfg(rustc_1_31)]
+    struct Token(u8);
+
+    #[test]
+    fn returns_bindings() {
+        let event = Event::Login {
+            user: "alice",
+            ts: 7,
+        };
+        let (user, ts) = assert_matches!(event, Event::Login { user, ts, .. } => (user, ts));
+        assert_eq!((user, ts), ("alice", 7));
+
+        let events = [Event::Logout { ts: 3 }, Event::Login { user: "bob", ts: 9 }];
+        let ts = assert_matches!(&events[0], Event::Login { ts, .. } | Event::Logout { ts } if *ts > 2 => ts);
+        assert_eq!(*ts, 3);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rustc_1_30),
+        ignore = "guard failures are only reported in rustc 1.30.0 or later"
+    )]
+    #[should_panic(
+        expected = r#"assertion failed, expression matches one of the given variants, but the guard does not hold.
+    expression: Logout { ts: 3 }
+    variant: Event::Logout { ts }
+    guard: ts > 5"#
+    )]
+    fn guard_panic_message() {
+        assert_matches!(Event::Logout { ts: 3 }, Event::Login { ts, .. } | Event::Logout { ts } if ts > 5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression does not match any of the given variants.
+    expression: Logout { ts: 3 }
+    variants: Event::Login { user, .. } if user.len() > 3: checking alice"#
+    )]
+    fn custom_panic_message() {
+        let _user = assert_matches!(
+            Event::Logout { ts: 3 },
+            Event::Login { user, .. } if user.len() > 3 => user,
+            "checking {}",
+            "alice"
+        );
+    }
+
+    #[test]
+    #[cfg(rustc_1_31)]
+    #[cfg_attr(
+        not(rustc_1_38),
+        ignore = "type names are only available in rustc 1.38.0 or later"
+    )]
+    #[should_panic(
+        expected = r#"assertion failed, expression does not match any of the given variants.
+    expression: <claims::assert_matches::tests::Token: no Debug impl>
+    variants: Token(0)"#
+    )]
+    fn does_not_require_debug() {
+        assert_matches!(Token(1), Token(0));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rustc_1_30),
+        ignore = "mismatches are only reported in rustc 1.30.0 or later"
+    )]
+    #[should_panic(
+        expected = r#"assertion failed, expression does not match any of the given variants.
+    expression: Ok(Response { status: 404, body: Some("missing") })
+    variants: Ok(Response { status: 200, body: Some(_) })
+    mismatch: .0.status does not match 200"#
+    )]
+    fn mismatch_panic_message() {
+        let response: Result<Response, ()> = Ok(Response {
+            status: 404,
+            body: Some("missing"),
+        });
+        assert_matches!(
+            response,
+            Ok(Response {
+                status: 200,
+                body: Some(_)
+            })
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rustc_1_30),
+        ignore = "mismatches are only reported in rustc 1.30.0 or later"
+    )]
+    #[should_panic(
+        expected = r#"assertion failed, expression does not match any of the given variants.
+    expression: (Some(Logout { ts: 3 }), 1)
+    variants: (Some(Event::Logout { ts: 4 }), 1) if true
+    mismatch: .0.0.ts does not match 4: checking ts"#
+    )]
+    fn nested_mismatch_panic_message() {
+        assert_matches!(
+            (Some(Event::Logout { ts: 3 }), 1),
+            (Some(Event::Logout { ts: 4 }), 1) if true,
+            "checking ts"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rustc_1_30),
+        ignore = "mismatches are only reported in rustc 1.30.0 or later"
+    )]
+    #[should_panic(
+        expected = r#"assertion failed, expression does not match any of the given variants.
+    expression: Err(())
+    variants: Ok(Response { status: 200, .. })"#
+    )]
+    fn outer_mismatch_panic_message() {
+        let response: Result<Response, ()> = Err(());
+        assert_matches!(response, Ok(Response { status: 200, .. }));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"variants: Some(Response { status: 200, .. }) | None"#)]
+    fn no_mismatch_for_several_variants() {
+        let response = Some(Response {
+            status: 404,
+            body: None,
+        });
+        assert_matches!(response, Some(Response { status: 200, .. }) | None);
+    }
+}
@@ -0,0 +1,173 @@
+/// Asserts that the values of an iterator are monotonic in the given direction.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Accepts any [`IntoIterator`], such as slices, vectors or iterators,
+/// and requires its items to implement [`PartialOrd`] and [`Debug`].
+/// The direction is one of:
+///
+/// * `increasing`, every value is greater than or equal to the previous one,
+/// * `strictly increasing`, every value is greater than the previous one,
+/// * `decreasing`, every value is less than or equal to the previous one,
+/// * `strictly decreasing`, every value is less than the previous one.
+///
+/// Optionally, `max_step = step` limits how far apart consecutive values may be.
+/// The step between two values is their difference, computed with [`Sub`] on the type of
+/// `max_step`, which the values must be [borrowable] as. Both values and references
+/// to values can be checked this way.
+///
+/// On failure, the first pair of consecutive values that breaks the sequence is reported,
+/// along with their indices. The values are not collected, so this macro works without an allocator.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_monotonic!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let counters = [1, 1, 3, 7];
+///
+/// assert_monotonic!(&counters, increasing);
+/// assert_monotonic!(&counters, increasing, max_step = 5);
+/// assert_monotonic!(counters.iter().rev(), decreasing);
+/// assert_monotonic!(&[0.5, 0.25, 0.0], strictly decreasing, max_step = 0.25);
+///
+/// // With custom messages
+/// assert_monotonic!(&counters, increasing, "Expecting {} to only grow", "counters");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_monotonic!(&[1, 3, 10], strictly increasing, max_step = 5);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`Sub`]: https://doc.rust-lang.org/core/ops/trait.Sub.html
+/// [borrowable]: https://doc.rust-lang.org/core/borrow/trait.Borrow.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_monotonic!`]: ./macro.debug_assert_monotonic.html
+#[macro_export]
+macro_rules! assert_monotonic {
+    (@direction $values:expr, strictly increasing $($rest:tt)*) => {
+        $crate::assert_monotonic!(@step $values, StrictlyIncreasing $($rest)*)
+    };
+    (@direction $values:expr, increasing $($rest:tt)*) => {
+        $crate::assert_monotonic!(@step $values, Increasing $($rest)*)
+    };
+    (@direction $values:expr, strictly decreasing $($rest:tt)*) => {
+        $crate::assert_monotonic!(@step $values, StrictlyDecreasing $($rest)*)
+    };
+    (@direction $values:expr, decreasing $($rest:tt)*) => {
+        $crate::assert_monotonic!(@step $values, Decreasing $($rest)*)
+    };
+    (@direction $values:expr, $($rest:tt)*) => {
+        compile_error!("expected `increasing`, `strictly increasing`, `decreasing` or `strictly decreasing`")
+    };
+
+    (@step $values:expr, $direction:ident, max_step = $max_step:expr) => {
+        if let Err(violation) = $crate::__private::check_monotonic_step($values, $crate::__private::Direction::$direction, $max_step) {
+            panic!("assertion failed: `({} is monotonic)`, {}", stringify!($values), violation);
+        }
+    };
+    (@step $values:expr, $direction:ident, max_step = $max_step:expr,) => {
+        $crate::assert_monotonic!(@step $values, $direction, max_step = $max_step)
+    };
+    (@step $values:expr, $direction:ident, max_step = $max_step:expr, $($arg:tt)+) => {
+        if let Err(violation) = $crate::__private::check_monotonic_step($values, $crate::__private::Direction::$direction, $max_step) {
+            panic!("assertion failed: `({} is monotonic)`, {}: {}", stringify!($values), violation, format_args!($($arg)+));
+        }
+    };
+    (@step $values:expr, $direction:ident) => {
+        if let Err(violation) = $crate::__private::check_monotonic($values, $crate::__private::Direction::$direction) {
+            panic!("assertion failed: `({} is monotonic)`, {}", stringify!($values), violation);
+        }
+    };
+    (@step $values:expr, $direction:ident,) => {
+        $crate::assert_monotonic!(@step $values, $direction)
+    };
+    (@step $values:expr, $direction:ident, $($arg:tt)+) => {
+        if let Err(violation) = $crate::__private::check_monotonic($values, $crate::__private::Direction::$direction) {
+            panic!("assertion failed: `({} is monotonic)`, {}: {}", stringify!($values), violation, format_args!($($arg)+));
+        }
+    };
+
+    ($values:expr, $($rest:tt)+) => {
+        $crate::assert_monotonic!(@direction $values, $($rest)+)
+    };
+}
+
+/// Asserts that the values of an iterator are monotonic in the given direction in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_monotonic!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_monotonic!`]: ./macro.assert_monotonic.html
+#[macro_export]
+macro_rules! debug_assert_monotonic {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_monotonic!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(&[1, 2, 2, 3] is monotonic)`, values at indices `1` and `2` are not strictly increasing
+    [1]: `2`,
+    [2]: `2`"#
+    )]
+    fn default_panic_message() {
+        assert_monotonic!(&[1, 2, 2, 3], strictly increasing);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(&[10u8, 8, 1] is monotonic)`, step between values at indices `1` and `2` exceeds `max_step`
+    [1]: `8`,
+    [2]: `1`,
+    step: `7`,
+    max_step: `5`"#
+    )]
+    fn max_step_panic_message() {
+        assert_monotonic!(&[10u8, 8, 1], decreasing, max_step = 5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(&[2.0, 1.0] is monotonic)`, values at indices `0` and `1` are not increasing
+    [0]: `2.0`,
+    [1]: `1.0`: checking 2"#
+    )]
+    fn custom_panic_message() {
+        assert_monotonic!(&[2.0, 1.0], increasing, max_step = 1.0, "checking {}", 2);
+    }
+
+    #[test]
+    fn monotonic() {
+        assert_monotonic!(core::iter::empty::<u32>(), strictly decreasing);
+        assert_monotonic!(0..10, strictly increasing, max_step = 1,);
+        assert_monotonic!(&[3, 3, 0], decreasing,);
+    }
+}
@@ -0,0 +1,96 @@
+/// Asserts that two expressions differ by no more than the given absolute tolerance.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// This is a shorthand for [`assert_approx_eq!`] with the `abs = <f64>` tolerance,
+/// and has the same requirements and failure message.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_near!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_near!(3.14159, 3.14, 0.01);
+///
+/// // With custom messages
+/// assert_near!(3.14159, 3.14, 0.01, "Expecting {} to be close to pi", 3.14);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_near!(3.14159, 3.0, 0.01);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_approx_eq!`]: ./macro.assert_approx_eq.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_near!`]: ./macro.debug_assert_near.html
+#[macro_export]
+macro_rules! assert_near {
+    ($left:expr, $right:expr, $epsilon:expr) => {
+        $crate::assert_approx_eq!($left, $right, abs = $epsilon);
+    };
+    ($left:expr, $right:expr, $epsilon:expr,) => {
+        $crate::assert_near!($left, $right, $epsilon);
+    };
+    ($left:expr, $right:expr, $epsilon:expr, $($arg:tt)+) => {
+        $crate::assert_approx_eq!($left, $right, abs = $epsilon, $($arg)+);
+    };
+}
+
+/// Asserts that two expressions differ by no more than the given absolute tolerance in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_near!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_near!`]: ./macro.assert_near.html
+#[macro_export]
+macro_rules! debug_assert_near {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_near!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn within_tolerance() {
+        assert_near!(1.2345, 1.23, 0.01);
+        assert_near!(2.0f32, 2.05, 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(left ~= right)`
+    left: `3.5`,
+    right: `3.0`,
+    tolerance: `Absolute(0.25)`,
+    reason: difference `0.5` exceeds tolerance"#)]
+    fn default_panic_message() {
+        assert_near!(3.5, 3.0, 0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = "reason: difference `0.5` exceeds tolerance: checking 3.5")]
+    fn custom_panic_message() {
+        assert_near!(3.5, 3.0, 0.25, "checking {:?}", 3.5);
+    }
+}
@@ -39,6 +39,31 @@
 /// [`None`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.None
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_none!`]: ./macro.debug_assert_none.html
+#[cfg(rustc_1_30)]
+#[macro_export]
+macro_rules! assert_none {
+    ($cond:expr,) => {
+        $crate::assert_none!($cond);
+    };
+    ($cond:expr) => {
+        match $cond {
+            n @ None => n,
+            t @ Some(..) => {
+                panic!("assertion failed, expected None, got {:?}", $crate::__debug_value!(t));
+            }
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        match $cond {
+            n @ None => n,
+            t @ Some(..) => {
+                panic!("assertion failed, expected None, got {:?}: {}", $crate::__debug_value!(t), format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+#[cfg(not(rustc_1_30))]
 #[macro_export]
 macro_rules! assert_none {
     ($cond:expr,) => {
@@ -79,3 +104,22 @@ macro_rules! assert_none {
 macro_rules! debug_assert_none {
     ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_none!($($arg)*); })
 }
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    #[cfg(rustc_1_31)]
+    #[cfg_attr(
+        not(rustc_1_38),
+        ignore = "type names are only available in rustc 1.38.0 or later"
+    )]
+    #[should_panic(
+        expected = "assertion failed, expected None, got <core::option::Option<claims::assert_none::tests::does_not_require_debug::Foo>: no Debug impl>"
+    )]
+    fn does_not_require_debug() {
+        struct Foo;
+
+        let _ = assert_none!(Some(Foo));
+    }
+}
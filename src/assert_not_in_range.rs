@@ -0,0 +1,124 @@
+/// Asserts that expression is not contained in the given range.
+///
+/// This macro is available for Rust 1.35+.
+///
+/// Accepts any [`RangeBounds`] implementation (`a..b`, `a..=b`, `a..`, `..b`, `..=b`, `..`).
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_not_in_range!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_not_in_range!(10, 3..10);
+/// assert_not_in_range!(2, 3..);
+///
+/// // With custom messages
+/// assert_not_in_range!(10, ..10, "Expecting {} to be at least {}", 10, 10);
+/// # }
+/// ```
+///
+/// The checked value will be returned from the macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let value = assert_not_in_range!(10, 3..10);
+/// assert_eq!(value, 10);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_not_in_range!(5, 3..10);  // Will panic
+/// # }
+/// ```
+///
+/// [`RangeBounds`]: https://doc.rust-lang.org/core/ops/trait.RangeBounds.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_not_in_range!`]: ./macro.debug_assert_not_in_range.html
+#[macro_export]
+macro_rules! assert_not_in_range {
+    ($value:expr, $range:expr,) => {
+        $crate::assert_not_in_range!($value, $range)
+    };
+    ($value:expr, $range:expr) => {
+        match ($value, &$range) {
+            (value, range) => {
+                if core::ops::RangeBounds::contains(range, &value) {
+                    panic!(r#"assertion failed: `(value not in range)`
+    value: `{:?}`,
+    range: `{:?}`"#, $crate::__debug_value!(value), $crate::__debug_value!(range));
+                }
+                value
+            }
+        }
+    };
+    ($value:expr, $range:expr, $($arg:tt)+) => {
+        match ($value, &$range) {
+            (value, range) => {
+                if core::ops::RangeBounds::contains(range, &value) {
+                    panic!(r#"assertion failed: `(value not in range)`
+    value: `{:?}`,
+    range: `{:?}`: {}"#, $crate::__debug_value!(value), $crate::__debug_value!(range), format_args!($($arg)+));
+                }
+                value
+            }
+        }
+    };
+}
+
+/// Asserts that expression is not contained in the given range in runtime.
+///
+/// This macro is available for Rust 1.35+.
+///
+/// Like [`assert_not_in_range!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_not_in_range!`]: ./macro.assert_not_in_range.html
+#[macro_export]
+macro_rules! debug_assert_not_in_range {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_not_in_range!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    fn outside_of_range() {
+        assert_eq!(assert_not_in_range!(10, 3..10), 10);
+        assert_eq!(assert_not_in_range!(2.5, 3.0..), 2.5);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(value not in range)`
+    value: `5`,
+    range: `3..10`"#)]
+    fn default_panic_message() {
+        let _ = assert_not_in_range!(5, 3..10);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed: `(value not in range)`
+    value: `7`,
+    range: `..=7`: expecting 7 to be above 7"#)]
+    fn custom_panic_message() {
+        let _ = assert_not_in_range!(7, ..=7, "expecting {} to be above {}", 7, 7);
+    }
+}
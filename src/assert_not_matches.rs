@@ -0,0 +1,202 @@
+/// Asserts that expression does not match any of the given variants.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// It works exactly as `!`[`std::matches!`] would,
+/// except it panics if there is a match, reporting which of the variants matched.
+///
+/// ## Guards
+///
+/// Before Rust 1.39, a pattern followed by a guard cannot bind values by move,
+/// so values that are not `Copy` have to be bound with `ref` there.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_not_matches!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let foo = '1';
+/// assert_not_matches!(foo, 'A'..='Z' | 'a'..='z');
+///
+/// // With custom messages
+/// assert_not_matches!(foo, 'A'..='Z' | 'a'..='z', "expecting it not to be letter: {}", foo);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let bar: Option<i32> = Some(3);
+/// assert_not_matches!(bar, Some(x) if x > 2);  // Will panic
+/// # }
+/// ```
+///
+/// [`std::matches!`]: https://doc.rust-lang.org/stable/std/macro.matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_not_matches!`]: ./macro.debug_assert_not_matches.html
+#[macro_export]
+macro_rules! assert_not_matches {
+    // The matching variant is recorded by its guard, which then fails, so that the value is never
+    // moved into the bindings of the pattern and can still be printed.
+    ($expression:expr, $( $pattern:pat )|+) => {
+        match $expression {
+            value => {
+                let matched = $crate::__private::Cell::new(None);
+                match value {
+                    $(
+                        $pattern if {
+                            if matched.get().is_none() {
+                                matched.set(Some(stringify!($pattern)));
+                            }
+                            false
+                        } => unreachable!(),
+                    )+
+                    _ => {},
+                }
+                if let Some(variant) = matched.get() {
+                    panic!(r#"assertion failed, expression matches one of the given variants.
+    expression: {:?}
+    variant: {}"#, $crate::__debug_value!(value), variant);
+                }
+            }
+        }
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard: expr) => {
+        match $expression {
+            value => {
+                let matched = $crate::__private::Cell::new(None);
+                match value {
+                    $(
+                        $pattern if $guard && {
+                            if matched.get().is_none() {
+                                matched.set(Some(stringify!($pattern if $guard)));
+                            }
+                            false
+                        } => unreachable!(),
+                    )+
+                    _ => {},
+                }
+                if let Some(variant) = matched.get() {
+                    panic!(r#"assertion failed, expression matches one of the given variants.
+    expression: {:?}
+    variant: {}"#, $crate::__debug_value!(value), variant);
+                }
+            }
+        }
+    };
+    ($expression:expr, $( $pattern:pat )|+, $($arg:tt)+) => {
+        match $expression {
+            value => {
+                let matched = $crate::__private::Cell::new(None);
+                match value {
+                    $(
+                        $pattern if {
+                            if matched.get().is_none() {
+                                matched.set(Some(stringify!($pattern)));
+                            }
+                            false
+                        } => unreachable!(),
+                    )+
+                    _ => {},
+                }
+                if let Some(variant) = matched.get() {
+                    panic!(r#"assertion failed, expression matches one of the given variants.
+    expression: {:?}
+    variant: {}: {}"#, $crate::__debug_value!(value), variant, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard: expr, $($arg:tt)+) => {
+        match $expression {
+            value => {
+                let matched = $crate::__private::Cell::new(None);
+                match value {
+                    $(
+                        $pattern if $guard && {
+                            if matched.get().is_none() {
+                                matched.set(Some(stringify!($pattern if $guard)));
+                            }
+                            false
+                        } => unreachable!(),
+                    )+
+                    _ => {},
+                }
+                if let Some(variant) = matched.get() {
+                    panic!(r#"assertion failed, expression matches one of the given variants.
+    expression: {:?}
+    variant: {}: {}"#, $crate::__debug_value!(value), variant, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that expression does not match any of the given variants in runtime.
+///
+/// Like [`assert_not_matches!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_not_matches!`]: ./macro.assert_not_matches.html
+#[macro_export]
+macro_rules! debug_assert_not_matches {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_not_matches!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    enum Event {
+        Login(&'static str),
+        Logout(&'static str),
+        Timeout,
+    }
+
+    #[test]
+    fn does_not_match() {
+        assert_not_matches!(Event::Timeout, Event::Login(_) | Event::Logout(_));
+        assert_not_matches!(Event::Login("root"), Event::Login(user) | Event::Logout(user) if user.len() > 4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression matches one of the given variants.
+    expression: Logout("alice")
+    variant: Event::Logout(_)"#
+    )]
+    fn default_panic_message() {
+        assert_not_matches!(Event::Logout("alice"), Event::Login(_) | Event::Logout(_));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, expression matches one of the given variants.
+    expression: Logout("alice")
+    variant: Event::Logout(user) if user.len() > 4: checking alice"#
+    )]
+    fn custom_panic_message() {
+        assert_not_matches!(
+            Event::Logout("alice"),
+            Event::Login(user) | Event::Logout(user) if user.len() > 4,
+            "checking {}",
+            "alice"
+        );
+    }
+}
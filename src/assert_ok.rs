@@ -50,6 +50,31 @@
 /// [`Ok(T)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_ok!`]: ./macro.debug_assert_ok.html
+#[cfg(rustc_1_30)]
+#[macro_export]
+macro_rules! assert_ok {
+    ($cond:expr,) => {
+        $crate::assert_ok!($cond);
+    };
+    ($cond:expr) => {
+        match $cond {
+            Ok(t) => t,
+            Err(e) => {
+                panic!("assertion failed, expected Ok(..), got Err({:?})", $crate::__debug_value!(e));
+            }
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        match $cond {
+            Ok(t) => t,
+            Err(e) => {
+                panic!("assertion failed, expected Ok(..), got Err({:?}): {}", $crate::__debug_value!(e), format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+#[cfg(not(rustc_1_30))]
 #[macro_export]
 macro_rules! assert_ok {
     ($cond:expr,) => {
@@ -51,7 +51,37 @@
 /// [`Ok(T)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
 /// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
 /// [`debug_assert_ok_eq!`]: ./macro.debug_assert_ok_eq.html
-#[cfg(rustc_1_11)]
+#[cfg(rustc_1_30)]
+#[macro_export]
+macro_rules! assert_ok_eq {
+    ($cond:expr, $expected:expr,) => {
+        $crate::assert_ok_eq!($cond, $expected);
+    };
+    ($cond:expr, $expected:expr) => {
+        match $cond {
+            Ok(t) => {
+                assert_eq!(t, $expected);
+                t
+            },
+            e @ Err(..) => {
+                panic!("assertion failed, expected Ok(..), got {:?}", $crate::__debug_value!(e));
+            }
+        }
+    };
+    ($cond:expr, $expected:expr, $($arg:tt)+) => {
+        match $cond {
+            Ok(t) => {
+                assert_eq!(t, $expected, $($arg)+);
+                t
+            },
+            e @ Err(..) => {
+                panic!("assertion failed, expected Ok(..), got {:?}: {}", $crate::__debug_value!(e), format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+#[cfg(all(rustc_1_11, not(rustc_1_30)))]
 #[macro_export]
 macro_rules! assert_ok_eq {
     ($cond:expr, $expected:expr,) => {
@@ -141,4 +171,19 @@ mod tests {
     fn custom_message_propagation() {
         let _ = assert_ok_eq!(Ok::<_, ()>(1), 2, "foo");
     }
+
+    #[test]
+    #[cfg(rustc_1_31)]
+    #[cfg_attr(
+        not(rustc_1_38),
+        ignore = "type names are only available in rustc 1.38.0 or later"
+    )]
+    #[should_panic(
+        expected = "assertion failed, expected Ok(..), got <core::result::Result<i32, claims::assert_ok_eq::tests::does_not_require_err_debug::Foo>: no Debug impl>"
+    )]
+    fn does_not_require_err_debug() {
+        struct Foo;
+
+        let _ = assert_ok_eq!(Err::<i32, _>(Foo), 1);
+    }
 }
@@ -0,0 +1,166 @@
+/// Asserts that expression returns [`Ok(T)`] variant, and that `T` matches any of the given variants.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// It works like [`assert_matches!`] applied to the value inside of `Ok(..)`,
+/// and supports its guards and bindings: the pattern can be followed by `=> expression`,
+/// which is evaluated with the bindings of the pattern in scope and returned by the macro.
+///
+/// The panic message tells whether the expression is an `Err(..)` variant,
+/// or whether the value inside of `Ok(..)` does not match the pattern.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ok_matches!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<(u16, &str), ()> = Ok((200, "OK"));
+///
+/// assert_ok_matches!(res, (200..=299, _));
+///
+/// // With custom messages
+/// assert_ok_matches!(res, (200..=299, _), "expecting success for {:?}", res);
+///
+/// // Returning the bindings
+/// let reason = assert_ok_matches!(res, (status, reason) if status != 204 => reason);
+/// assert_eq!(reason, "OK");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<i32, ()> = Ok(404);
+///
+/// assert_ok_matches!(res, 200..=299);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ok(T)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ok_matches!`]: ./macro.debug_assert_ok_matches.html
+#[macro_export]
+macro_rules! assert_ok_matches {
+    (@ok $expression:expr, $variants:tt, $result:tt, [$($arg:tt)*]) => {
+        match $expression {
+            Ok(value) => $crate::assert_matches!(@unwrapped "Ok", value, $variants, $result, [$($arg)*]),
+            Err(e) => {
+                $crate::assert_matches!(@panic ["assertion failed, expected Ok(..), got Err({:?})", $crate::__debug_value!(e)] [$($arg)*])
+            }
+        }
+    };
+
+    ($expression:expr, $( $pattern:pat )|+) => {
+        $crate::assert_ok_matches!(@ok $expression, [$($pattern)|+], [()], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr) => {
+        $crate::assert_ok_matches!(@ok $expression, [$($pattern)|+ if $guard], [()], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+, $($arg:tt)+) => {
+        $crate::assert_ok_matches!(@ok $expression, [$($pattern)|+], [()], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr, $($arg:tt)+) => {
+        $crate::assert_ok_matches!(@ok $expression, [$($pattern)|+ if $guard], [()], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ => $result:expr) => {
+        $crate::assert_ok_matches!(@ok $expression, [$($pattern)|+], [$result], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr => $result:expr) => {
+        $crate::assert_ok_matches!(@ok $expression, [$($pattern)|+ if $guard], [$result], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ => $result:expr, $($arg:tt)+) => {
+        $crate::assert_ok_matches!(@ok $expression, [$($pattern)|+], [$result], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr => $result:expr, $($arg:tt)+) => {
+        $crate::assert_ok_matches!(@ok $expression, [$($pattern)|+ if $guard], [$result], [$($arg)+])
+    };
+}
+
+/// Asserts that expression returns [`Ok(T)`] variant, and that `T` matches any of the given variants
+/// in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_ok_matches!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Ok(T)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Ok
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_ok_matches!`]: ./macro.assert_ok_matches.html
+#[macro_export]
+macro_rules! debug_assert_ok_matches {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_ok_matches!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    enum Response {
+        Found { id: u32, name: &'static str },
+        Empty,
+    }
+
+    #[test]
+    fn returns_bindings() {
+        let res: Result<Response, ()> = Ok(Response::Found {
+            id: 7,
+            name: "alice",
+        });
+        let (id, name) =
+            assert_ok_matches!(res, Response::Found { id, name } if id > 0 => (id, name));
+        assert_eq!((id, name), (7, "alice"));
+
+        assert_ok_matches!(
+            Ok::<_, ()>(Response::Empty),
+            Response::Empty | Response::Found { id: 0, .. }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ok(..), got Err(\"timeout\")")]
+    fn wrong_variant() {
+        assert_ok_matches!(Err::<Response, _>("timeout"), Response::Empty);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, Ok value does not match any of the given variants.
+    value: Empty
+    variants: Response::Found { .. }"#
+    )]
+    fn pattern_mismatch() {
+        assert_ok_matches!(Ok::<_, ()>(Response::Empty), Response::Found { .. });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, Ok value matches one of the given variants, but the guard does not hold.
+    value: Found { id: 7, name: "alice" }
+    variant: Response::Found { id, .. }
+    guard: id > 10: checking alice"#
+    )]
+    fn guard_panic_message() {
+        let res: Result<Response, ()> = Ok(Response::Found {
+            id: 7,
+            name: "alice",
+        });
+        assert_ok_matches!(res, Response::Found { id, .. } if id > 10, "checking {}", "alice");
+    }
+}
@@ -0,0 +1,140 @@
+/// Asserts that the [`Ord`] implementation of the given sample values obeys its laws.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Accepts anything that can be sliced with `[..]`, such as arrays, slices and vectors,
+/// and requires the values to implement [`Ord`] and [`Debug`].
+///
+/// In addition to the laws checked by [`assert_partial_ord_laws!`], checks that every value
+/// compares equal to itself (reflexivity), that every pair of values is comparable (totality),
+/// and that [`Ord::cmp`] agrees with [`PartialOrd::partial_cmp`].
+///
+/// Every pair and triple of samples is checked. On failure, the smallest counterexample
+/// is reported along with the violated law.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ord_laws!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_ord_laws!([3, 1, 2, 1]);
+/// assert_ord_laws!(&["b", "a", "c"][..]);
+///
+/// // With custom messages
+/// assert_ord_laws!([Some(1), None], "Checking {}", "options");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// use std::cmp::Ordering;
+///
+/// #[derive(Debug, PartialEq, Eq, PartialOrd)]
+/// struct Version(u32);
+///
+/// impl Ord for Version {
+///     fn cmp(&self, other: &Self) -> Ordering {
+///         other.0.cmp(&self.0)
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_ord_laws!([Version(1), Version(2)]);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Ord::cmp`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html#tymethod.cmp
+/// [`PartialOrd::partial_cmp`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#tymethod.partial_cmp
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_partial_ord_laws!`]: ./macro.assert_partial_ord_laws.html
+/// [`debug_assert_ord_laws!`]: ./macro.debug_assert_ord_laws.html
+#[macro_export]
+macro_rules! assert_ord_laws {
+    ($samples:expr,) => {
+        $crate::assert_ord_laws!($samples);
+    };
+    ($samples:expr) => {
+        match &$samples {
+            samples => {
+                if let Err(violation) = $crate::__private::check_ord_laws(&samples[..]) {
+                    panic!("assertion failed, `Ord` laws do not hold: {}", violation);
+                }
+            }
+        }
+    };
+    ($samples:expr, $($arg:tt)+) => {
+        match &$samples {
+            samples => {
+                if let Err(violation) = $crate::__private::check_ord_laws(&samples[..]) {
+                    panic!("assertion failed, `Ord` laws do not hold: {}: {}", violation, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the [`Ord`] implementation of the given sample values obeys its laws in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_ord_laws!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_ord_laws!`]: ./macro.assert_ord_laws.html
+#[macro_export]
+macro_rules! debug_assert_ord_laws {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_ord_laws!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::cmp::Ordering;
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd)]
+    struct Reversed(u32);
+
+    impl Ord for Reversed {
+        fn cmp(&self, other: &Self) -> Ordering {
+            other.0.cmp(&self.0)
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, `Ord` laws do not hold: consistency, `a.partial_cmp(b)` is not `Some(a.cmp(b))`
+    a: `Reversed(1)` (at index 0),
+    b: `Reversed(2)` (at index 1),
+    a.partial_cmp(b): `Some(Less)`,
+    b.partial_cmp(a): `Some(Greater)`,
+    a.cmp(b): `Greater`"#
+    )]
+    fn default_panic_message() {
+        assert_ord_laws!([Reversed(1), Reversed(2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a.cmp(b): `Greater`: checking Reversed")]
+    fn custom_panic_message() {
+        assert_ord_laws!([Reversed(1), Reversed(2)], "checking {}", "Reversed");
+    }
+}
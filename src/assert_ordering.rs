@@ -0,0 +1,184 @@
+/// Asserts that comparing the first expression to the last one yields the given [`Ordering`].
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Requires that both expressions be comparable with [`PartialOrd::partial_cmp`].
+/// If the operands are of the same type implementing [`Ord`], this macro also asserts
+/// that [`Ord::cmp`] agrees with `partial_cmp`.
+///
+/// See [`assert_cmp_eq!`] and [`assert_partial_cmp_eq!`] for checking the result
+/// of only one of them.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ordering!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// use std::cmp::Ordering;
+///
+/// # fn main() {
+/// assert_ordering!(1, Ordering::Less, 2);
+/// assert_ordering!("b", Ordering::Greater, "a");
+///
+/// // With custom messages
+/// assert_ordering!(2.0, Ordering::Equal, 2.0, "Expecting {} to equal itself", 2.0);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// use std::cmp::Ordering;
+///
+/// # fn main() {
+/// assert_ordering!(1, Ordering::Greater, 2);  // Will panic
+/// # }
+/// ```
+///
+/// [`Ordering`]: https://doc.rust-lang.org/core/cmp/enum.Ordering.html
+/// [`PartialOrd::partial_cmp`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#tymethod.partial_cmp
+/// [`Ord`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html
+/// [`Ord::cmp`]: https://doc.rust-lang.org/core/cmp/trait.Ord.html#tymethod.cmp
+/// [`assert_cmp_eq!`]: ./macro.assert_cmp_eq.html
+/// [`assert_partial_cmp_eq!`]: ./macro.assert_partial_cmp_eq.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ordering!`]: ./macro.debug_assert_ordering.html
+#[macro_export]
+macro_rules! assert_ordering {
+    ($left:expr, $expected:expr, $right:expr,) => {
+        $crate::assert_ordering!($left, $expected, $right);
+    };
+    ($left:expr, $expected:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let expected: $crate::__private::Ordering = $expected;
+                let (partial, total) = {
+                    use $crate::__private::ActualOrdering;
+                    (&$crate::__private::OrderingOperands(&*left_val, &*right_val)).actual_ordering()
+                };
+                if let Some(total) = total {
+                    if partial != Some(total) {
+                        panic!(r#"assertion failed, `partial_cmp` and `cmp` disagree
+    left: `{:?}`,
+    right: `{:?}`,
+    partial_cmp: `{:?}`,
+    cmp: `{:?}`"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), partial, total)
+                    }
+                }
+                if partial != Some(expected) {
+                    panic!(r#"assertion failed: `(left.partial_cmp(right) == Some({:?}))`
+    left: `{:?}`,
+    right: `{:?}`,
+    ordering: `{:?}`"#, expected, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), partial)
+                }
+            }
+        }
+    };
+    ($left:expr, $expected:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let expected: $crate::__private::Ordering = $expected;
+                let (partial, total) = {
+                    use $crate::__private::ActualOrdering;
+                    (&$crate::__private::OrderingOperands(&*left_val, &*right_val)).actual_ordering()
+                };
+                if let Some(total) = total {
+                    if partial != Some(total) {
+                        panic!(r#"assertion failed, `partial_cmp` and `cmp` disagree
+    left: `{:?}`,
+    right: `{:?}`,
+    partial_cmp: `{:?}`,
+    cmp: `{:?}`: {}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), partial, total, format_args!($($arg)+))
+                    }
+                }
+                if partial != Some(expected) {
+                    panic!(r#"assertion failed: `(left.partial_cmp(right) == Some({:?}))`
+    left: `{:?}`,
+    right: `{:?}`,
+    ordering: `{:?}`: {}"#, expected, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), partial, format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that comparing the first expression to the last one yields the given [`Ordering`] in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_ordering!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/core/cmp/enum.Ordering.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_ordering!`]: ./macro.assert_ordering.html
+#[macro_export]
+macro_rules! debug_assert_ordering {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_ordering!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::cmp::Ordering;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Inconsistent;
+
+    impl PartialOrd for Inconsistent {
+        fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+            Some(Ordering::Less)
+        }
+    }
+
+    impl Ord for Inconsistent {
+        fn cmp(&self, _: &Self) -> Ordering {
+            Ordering::Greater
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(left.partial_cmp(right) == Some(Less))`
+    left: `NaN`,
+    right: `1.0`,
+    ordering: `None`"#
+    )]
+    fn default_panic_message() {
+        assert_ordering!("NaN".parse::<f64>().unwrap(), Ordering::Less, 1.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(left.partial_cmp(right) == Some(Greater))`
+    left: `1`,
+    right: `2`,
+    ordering: `Some(Less)`: comparing 1 to 2"#
+    )]
+    fn custom_panic_message() {
+        assert_ordering!(1, Ordering::Greater, 2, "comparing {} to {}", 1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed, `partial_cmp` and `cmp` disagree
+    left: `Inconsistent`,
+    right: `Inconsistent`,
+    partial_cmp: `Some(Less)`,
+    cmp: `Greater`"#)]
+    fn inconsistent_ord() {
+        assert_ordering!(Inconsistent, Ordering::Less, Inconsistent);
+    }
+}
@@ -0,0 +1,138 @@
+/// Asserts that [`PartialOrd::partial_cmp`] of the first expression with the second one returns
+/// the given `Option<Ordering>`.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Requires that both expressions be comparable with [`PartialOrd::partial_cmp`].
+/// Unlike the comparison macros, it can assert that the values are not comparable,
+/// by expecting `None`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_partial_cmp_eq!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// use std::cmp::Ordering;
+///
+/// # fn main() {
+/// assert_partial_cmp_eq!(1.0, 2.0, Some(Ordering::Less));
+/// assert_partial_cmp_eq!("NaN".parse::<f64>().unwrap(), 1.0, None);
+///
+/// // With custom messages
+/// assert_partial_cmp_eq!(2.0, 2.0, Some(Ordering::Equal), "Expecting {} to equal itself", 2.0);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// use std::cmp::Ordering;
+///
+/// # fn main() {
+/// assert_partial_cmp_eq!(1.0, 2.0, None);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialOrd::partial_cmp`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#tymethod.partial_cmp
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_partial_cmp_eq!`]: ./macro.debug_assert_partial_cmp_eq.html
+#[macro_export]
+macro_rules! assert_partial_cmp_eq {
+    ($left:expr, $right:expr, $expected:expr,) => {
+        $crate::assert_partial_cmp_eq!($left, $right, $expected);
+    };
+    ($left:expr, $right:expr, $expected:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let expected: Option<$crate::__private::Ordering> = $expected;
+                let actual = $crate::__private::PartialOrd::partial_cmp(left_val, right_val);
+                if actual != expected {
+                    panic!(r#"assertion failed: `(left.partial_cmp(right) == {:?})`
+    left: `{:?}`,
+    right: `{:?}`,
+    ordering: `{:?}`"#, expected, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), actual)
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $expected:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                let expected: Option<$crate::__private::Ordering> = $expected;
+                let actual = $crate::__private::PartialOrd::partial_cmp(left_val, right_val);
+                if actual != expected {
+                    panic!(r#"assertion failed: `(left.partial_cmp(right) == {:?})`
+    left: `{:?}`,
+    right: `{:?}`,
+    ordering: `{:?}`: {}"#, expected, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), actual, format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that [`PartialOrd::partial_cmp`] of the first expression with the second one returns
+/// the given `Option<Ordering>` in runtime.
+///
+/// Like [`assert_partial_cmp_eq!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_partial_cmp_eq!`]: ./macro.assert_partial_cmp_eq.html
+#[macro_export]
+macro_rules! debug_assert_partial_cmp_eq {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_partial_cmp_eq!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::cmp::Ordering;
+
+    #[test]
+    fn not_comparable() {
+        assert_partial_cmp_eq!("NaN".parse::<f64>().unwrap(), 1.0, None);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(left.partial_cmp(right) == Some(Less))`
+    left: `NaN`,
+    right: `1.0`,
+    ordering: `None`"#
+    )]
+    fn default_panic_message() {
+        assert_partial_cmp_eq!("NaN".parse::<f64>().unwrap(), 1.0, Some(Ordering::Less));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(left.partial_cmp(right) == Some(Greater))`
+    left: `1.5`,
+    right: `2.5`,
+    ordering: `Some(Less)`: comparing 1.5 to 2.5"#
+    )]
+    fn custom_panic_message() {
+        assert_partial_cmp_eq!(
+            1.5,
+            2.5,
+            Some(Ordering::Greater),
+            "comparing {} to {}",
+            1.5,
+            2.5
+        );
+    }
+}
@@ -0,0 +1,192 @@
+/// Asserts that the [`PartialOrd`] implementation of the given sample values obeys its laws.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Accepts anything that can be sliced with `[..]`, such as arrays, slices and vectors,
+/// and requires the values to implement [`PartialOrd`] and [`Debug`].
+///
+/// Checks that `==`, `!=`, `<`, `<=`, `>` and `>=` are consistent with [`PartialOrd::partial_cmp`],
+/// that `partial_cmp` is antisymmetric (`a.partial_cmp(b)` is the reverse of `b.partial_cmp(a)`)
+/// and that it is transitive. Incomparable values, such as `NaN`, are allowed.
+///
+/// Every pair and triple of samples is checked. On failure, the smallest counterexample
+/// is reported along with the violated law.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_partial_ord_laws!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let nan = "NaN".parse::<f64>().unwrap();
+/// assert_partial_ord_laws!([1.0, -0.0, 0.0, nan, 1.0 / 0.0]);
+///
+/// // With custom messages
+/// assert_partial_ord_laws!(["a", "b"], "Checking {}", "strings");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// use std::cmp::Ordering;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct AlwaysLess(i32);
+///
+/// impl PartialOrd for AlwaysLess {
+///     fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+///         Some(Ordering::Less)
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_partial_ord_laws!([AlwaysLess(1), AlwaysLess(2)]);  // Will panic
+/// # }
+/// ```
+///
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`PartialOrd::partial_cmp`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html#tymethod.partial_cmp
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_partial_ord_laws!`]: ./macro.debug_assert_partial_ord_laws.html
+#[macro_export]
+macro_rules! assert_partial_ord_laws {
+    ($samples:expr,) => {
+        $crate::assert_partial_ord_laws!($samples);
+    };
+    ($samples:expr) => {
+        match &$samples {
+            samples => {
+                if let Err(violation) = $crate::__private::check_partial_ord_laws(&samples[..]) {
+                    panic!("assertion failed, `PartialOrd` laws do not hold: {}", violation);
+                }
+            }
+        }
+    };
+    ($samples:expr, $($arg:tt)+) => {
+        match &$samples {
+            samples => {
+                if let Err(violation) = $crate::__private::check_partial_ord_laws(&samples[..]) {
+                    panic!("assertion failed, `PartialOrd` laws do not hold: {}: {}", violation, format_args!($($arg)+));
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that the [`PartialOrd`] implementation of the given sample values obeys its laws in runtime.
+///
+/// This macro is available for Rust 1.31+.
+///
+/// Like [`assert_partial_ord_laws!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_partial_ord_laws!`]: ./macro.assert_partial_ord_laws.html
+#[macro_export]
+macro_rules! debug_assert_partial_ord_laws {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_partial_ord_laws!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::cmp::Ordering;
+
+    // Compares by the first field only, but considers values equal by both fields.
+    #[derive(Debug, PartialEq)]
+    struct ByKey(i32, i32);
+
+    impl PartialOrd for ByKey {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.0.partial_cmp(&other.0)
+        }
+    }
+
+    // Orders values by their distance modulo 3, which is not transitive.
+    #[derive(Debug, PartialEq)]
+    struct Cyclic(i32);
+
+    impl PartialOrd for Cyclic {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            match ((other.0 - self.0) % 3 + 3) % 3 {
+                0 => Some(Ordering::Equal),
+                1 => Some(Ordering::Less),
+                _ => Some(Ordering::Greater),
+            }
+        }
+    }
+
+    // Is both equal and not equal to itself.
+    #[derive(Debug)]
+    struct Unequal;
+
+    impl PartialEq for Unequal {
+        fn eq(&self, _: &Self) -> bool {
+            true
+        }
+
+        fn ne(&self, _: &Self) -> bool {
+            true
+        }
+    }
+
+    impl PartialOrd for Unequal {
+        fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+            Some(Ordering::Equal)
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, `PartialOrd` laws do not hold: consistency with `PartialEq`, `a == b` does not match `a.partial_cmp(b) == Some(Equal)`
+    a: `ByKey(1, 2)` (at index 1),
+    b: `ByKey(1, 3)` (at index 2),
+    a.partial_cmp(b): `Some(Equal)`,
+    b.partial_cmp(a): `Some(Equal)`"#
+    )]
+    fn default_panic_message() {
+        assert_partial_ord_laws!([ByKey(0, 0), ByKey(1, 2), ByKey(1, 3)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, `PartialOrd` laws do not hold: consistency with `PartialEq`, `a != b` is not the negation of `a == b`
+    a: `Unequal` (at index 0),
+    b: `Unequal` (at index 0),
+    a.partial_cmp(b): `Some(Equal)`,
+    b.partial_cmp(a): `Some(Equal)`"#
+    )]
+    fn inconsistent_ne() {
+        assert_partial_ord_laws!([Unequal]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, `PartialOrd` laws do not hold: transitivity, `a.partial_cmp(c)` does not follow from `a.partial_cmp(b)` and `b.partial_cmp(c)`
+    a: `Cyclic(0)` (at index 0),
+    b: `Cyclic(1)` (at index 1),
+    c: `Cyclic(2)` (at index 2),
+    a.partial_cmp(b): `Some(Less)`,
+    b.partial_cmp(c): `Some(Less)`,
+    a.partial_cmp(c): `Some(Greater)`: checking cycles"#
+    )]
+    fn custom_panic_message() {
+        assert_partial_ord_laws!([Cyclic(0), Cyclic(1), Cyclic(2)], "checking {}", "cycles");
+    }
+}
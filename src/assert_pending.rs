@@ -65,7 +65,7 @@ macro_rules! assert_pending {
         match $cond {
             p @ core::task::Poll::Pending => p,
             r @ core::task::Poll::Ready(..) => {
-                panic!("assertion failed, expected Pending, got {:?}", r);
+                panic!("assertion failed, expected Pending, got {:?}", $crate::__debug_value!(r));
             }
         }
     };
@@ -62,7 +62,7 @@ macro_rules! assert_ready {
         match $cond {
             core::task::Poll::Ready(t) => t,
             p @ core::task::Poll::Pending => {
-                panic!("assertion failed, expected Ready(..), got {:?}", p);
+                panic!("assertion failed, expected Ready(..), got {:?}", $crate::__debug_value!(p));
             }
         }
     };
@@ -66,7 +66,7 @@ macro_rules! assert_ready_eq {
                 t
             },
             err_or_pending => {
-                panic!("assertion failed, expected Ready(Ok(..)), got {:?}", err_or_pending);
+                panic!("assertion failed, expected Ready(Ok(..)), got {:?}", $crate::__debug_value!(err_or_pending));
             }
         }
     };
@@ -77,7 +77,7 @@ macro_rules! assert_ready_eq {
                 t
             },
             err_or_pending => {
-                panic!("assertion failed, expected Ready(Ok(..)), got {:?}: {}", err_or_pending, format_args!($($arg)+));
+                panic!("assertion failed, expected Ready(Ok(..)), got {:?}: {}", $crate::__debug_value!(err_or_pending), format_args!($($arg)+));
             }
         }
     };
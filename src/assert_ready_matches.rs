@@ -0,0 +1,140 @@
+/// Asserts that expression returns [`Poll::Ready(T)`] variant, and that `T` matches any of the given variants.
+///
+/// This macro is available for Rust 1.36+.
+///
+/// It works like [`assert_matches!`] applied to the value inside of `Ready(..)`,
+/// and supports its guards and bindings: the pattern can be followed by `=> expression`,
+/// which is evaluated with the bindings of the pattern in scope and returned by the macro.
+///
+/// The panic message tells whether the expression is a `Pending` variant,
+/// or whether the value inside of `Ready(..)` does not match the pattern.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ready_matches!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<Result<usize, ()>> = Poll::Ready(Ok(42));
+///
+/// assert_ready_matches!(res, Ok(n) if n > 0);
+///
+/// // With custom messages
+/// assert_ready_matches!(res, Ok(_), "expecting {:?} to have succeeded", res);
+///
+/// // Returning the bindings
+/// let n = assert_ready_matches!(res, Ok(n) => n);
+/// assert_eq!(n, 42);
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<Result<usize, ()>> = Poll::Ready(Err(()));
+///
+/// assert_ready_matches!(res, Ok(_));  // Will panic
+/// # }
+/// ```
+///
+/// [`Poll::Ready(T)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ready_matches!`]: ./macro.debug_assert_ready_matches.html
+#[macro_export]
+macro_rules! assert_ready_matches {
+    (@ready $expression:expr, $variants:tt, $result:tt, [$($arg:tt)*]) => {
+        match $expression {
+            core::task::Poll::Ready(value) => $crate::assert_matches!(@unwrapped "Ready", value, $variants, $result, [$($arg)*]),
+            p @ core::task::Poll::Pending => {
+                $crate::assert_matches!(@panic ["assertion failed, expected Ready(..), got {:?}", $crate::__debug_value!(p)] [$($arg)*])
+            }
+        }
+    };
+
+    ($expression:expr, $( $pattern:pat )|+) => {
+        $crate::assert_ready_matches!(@ready $expression, [$($pattern)|+], [()], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr) => {
+        $crate::assert_ready_matches!(@ready $expression, [$($pattern)|+ if $guard], [()], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+, $($arg:tt)+) => {
+        $crate::assert_ready_matches!(@ready $expression, [$($pattern)|+], [()], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr, $($arg:tt)+) => {
+        $crate::assert_ready_matches!(@ready $expression, [$($pattern)|+ if $guard], [()], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ => $result:expr) => {
+        $crate::assert_ready_matches!(@ready $expression, [$($pattern)|+], [$result], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr => $result:expr) => {
+        $crate::assert_ready_matches!(@ready $expression, [$($pattern)|+ if $guard], [$result], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ => $result:expr, $($arg:tt)+) => {
+        $crate::assert_ready_matches!(@ready $expression, [$($pattern)|+], [$result], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr => $result:expr, $($arg:tt)+) => {
+        $crate::assert_ready_matches!(@ready $expression, [$($pattern)|+ if $guard], [$result], [$($arg)+])
+    };
+}
+
+/// Asserts that expression returns [`Poll::Ready(T)`] variant, and that `T` matches any of the given variants
+/// in runtime.
+///
+/// This macro is available for Rust 1.36+.
+///
+/// Like [`assert_ready_matches!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Poll::Ready(T)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_ready_matches!`]: ./macro.assert_ready_matches.html
+#[macro_export]
+macro_rules! debug_assert_ready_matches {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_ready_matches!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::task::Poll;
+
+    #[test]
+    fn returns_bindings() {
+        let n = assert_ready_matches!(Poll::Ready(Ok::<_, ()>(42)), Ok(n) if n > 0 => n);
+        assert_eq!(n, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ready(..), got Pending")]
+    fn wrong_variant() {
+        assert_ready_matches!(Poll::Pending::<Result<i32, ()>>, Ok(_));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, Ready value matches one of the given variants, but the guard does not hold.
+    value: Ok(0)
+    variant: Ok(n)
+    guard: n > 0: checking 0"#
+    )]
+    fn guard_panic_message() {
+        assert_ready_matches!(Poll::Ready(Ok::<_, ()>(0)), Ok(n) if n > 0, "checking {}", 0);
+    }
+}
@@ -0,0 +1,131 @@
+/// Asserts that left expression returns [`Poll::Ready(T)`] variant
+/// and its value of `T` type does not equal to the right expression.
+///
+/// This macro is available for Rust 1.36+.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_ready_ne!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<i32> = Poll::Ready(42);
+///
+/// assert_ready_ne!(res, 0);
+///
+/// // With custom messages
+/// assert_ready_ne!(res, 0, "Expecting a non-zero value, got {:?}", res);
+/// # }
+/// ```
+///
+/// Value of `T` type from the `Poll::Ready(T)` will also be returned from this macro call:
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<i32> = Poll::Ready(42);
+///
+/// let value = assert_ready_ne!(res, 0);
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+///
+/// [`Poll::Pending`] variant will cause panic:
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # use std::task::Poll;
+/// # fn main() {
+/// let res: Poll<i32> = Poll::Pending;
+///
+/// assert_ready_ne!(res, 0);  // Will panic
+/// # }
+/// ```
+///
+/// [`Poll::Ready(T)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`Poll::Pending`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Pending
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_ready_ne!`]: ./macro.debug_assert_ready_ne.html
+#[macro_export]
+macro_rules! assert_ready_ne {
+    ($cond:expr, $expected:expr,) => {
+        $crate::assert_ready_ne!($cond, $expected)
+    };
+    ($cond:expr, $expected:expr) => {
+        match $cond {
+            core::task::Poll::Ready(t) => {
+                assert_ne!(t, $expected);
+                t
+            },
+            p @ core::task::Poll::Pending => {
+                panic!("assertion failed, expected Ready(..), got {:?}", $crate::__debug_value!(p));
+            }
+        }
+    };
+    ($cond:expr, $expected:expr, $($arg:tt)+) => {
+        match $cond {
+            core::task::Poll::Ready(t) => {
+                assert_ne!(t, $expected, $($arg)+);
+                t
+            },
+            p @ core::task::Poll::Pending => {
+                panic!("assertion failed, expected Ready(..), got {:?}: {}", $crate::__debug_value!(p), format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that left expression returns [`Poll::Ready(T)`] variant
+/// and its value of `T` type does not equal to the right expression in runtime.
+///
+/// This macro is available for Rust 1.36+.
+///
+/// Like [`assert_ready_ne!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Poll::Ready(T)`]: https://doc.rust-lang.org/core/task/enum.Poll.html#variant.Ready
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_ready_ne!`]: ./macro.assert_ready_ne.html
+#[macro_export]
+macro_rules! debug_assert_ready_ne {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_ready_ne!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    use core::task::Poll;
+
+    #[test]
+    fn returns_value() {
+        assert_eq!(assert_ready_ne!(Poll::Ready(1), 2,), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Ready(..), got Pending")]
+    fn pending() {
+        let _ = assert_ready_ne!(Poll::<i32>::Pending, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "foo")]
+    fn custom_message_propagation() {
+        let _ = assert_ready_ne!(Poll::Ready(1), 1, "foo");
+    }
+}
@@ -72,7 +72,7 @@ macro_rules! assert_ready_ok {
         match $cond {
             core::task::Poll::Ready(Ok(t)) => t,
             err_or_pending => {
-                panic!("assertion failed, expected Ready(Ok(..)), got {:?}", err_or_pending);
+                panic!("assertion failed, expected Ready(Ok(..)), got {:?}", $crate::__debug_value!(err_or_pending));
             }
         }
     };
@@ -80,7 +80,7 @@ macro_rules! assert_ready_ok {
         match $cond {
             core::task::Poll::Ready(Ok(t)) => t,
             err_or_pending => {
-                panic!("assertion failed, expected Ready(Ok(..)), got {:?}: {}", err_or_pending, format_args!($($arg)+));
+                panic!("assertion failed, expected Ready(Ok(..)), got {:?}: {}", $crate::__debug_value!(err_or_pending), format_args!($($arg)+));
             }
         }
     };
@@ -0,0 +1,112 @@
+/// Asserts that both expressions are the same enum variant, whatever their fields are.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// The variants are compared with [`core::mem::discriminant`], so the fields of the variants
+/// do not need to implement [`PartialEq`].
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_same_variant!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let first: Result<i32, &str> = Err("timeout");
+/// let second: Result<i32, &str> = Err("connection reset");
+///
+/// assert_same_variant!(first, second);
+///
+/// // With custom messages
+/// assert_same_variant!(Some(1), Some(2), "expecting {}", "something");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_same_variant!(Some(1), None);  // Will panic
+/// # }
+/// ```
+///
+/// [`core::mem::discriminant`]: https://doc.rust-lang.org/core/mem/fn.discriminant.html
+/// [`PartialEq`]: https://doc.rust-lang.org/core/cmp/trait.PartialEq.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_same_variant!`]: ./macro.debug_assert_same_variant.html
+#[macro_export]
+macro_rules! assert_same_variant {
+    ($left:expr, $right:expr) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if $crate::__private::discriminant(&*left_val) != $crate::__private::discriminant(&*right_val) {
+                    panic!(r#"assertion failed: `(left and right are the same variant)`
+    left: `{:?}`,
+    right: `{:?}`"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val))
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr,) => {
+        $crate::assert_same_variant!($left, $right);
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if $crate::__private::discriminant(&*left_val) != $crate::__private::discriminant(&*right_val) {
+                    panic!(r#"assertion failed: `(left and right are the same variant)`
+    left: `{:?}`,
+    right: `{:?}`: {}"#, $crate::__debug_value!(*left_val), $crate::__debug_value!(*right_val), format_args!($($arg)+))
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that both expressions are the same enum variant, whatever their fields are, in runtime.
+///
+/// Like [`assert_same_variant!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_same_variant!`]: ./macro.assert_same_variant.html
+#[macro_export]
+macro_rules! debug_assert_same_variant {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_same_variant!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(left and right are the same variant)`
+    left: `Ok(1)`,
+    right: `Err("timeout")`"#
+    )]
+    fn default_panic_message() {
+        assert_same_variant!(Ok::<i32, &str>(1), Err::<i32, &str>("timeout"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(left and right are the same variant)`
+    left: `None`,
+    right: `Some(1)`: checking 1"#
+    )]
+    fn custom_panic_message() {
+        assert_same_variant!(None, Some(1), "checking {}", 1);
+    }
+}
@@ -0,0 +1,162 @@
+/// Asserts that expression returns [`Some(T)`] variant, and that `T` matches any of the given variants.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// It works like [`assert_matches!`] applied to the value inside of `Some(..)`,
+/// and supports its guards and bindings: the pattern can be followed by `=> expression`,
+/// which is evaluated with the bindings of the pattern in scope and returned by the macro.
+///
+/// The panic message tells whether the expression is a `None` variant,
+/// or whether the value inside of `Some(..)` does not match the pattern.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_some_matches!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// #[derive(Debug)]
+/// struct Item {
+///     name: &'static str,
+///     qty: u32,
+/// }
+///
+/// # fn main() {
+/// let item = Some(Item { name: "apple", qty: 3 });
+///
+/// assert_some_matches!(&item, Item { qty: 1..=5, .. });
+///
+/// // With custom messages
+/// assert_some_matches!(&item, Item { qty: 1..=5, .. }, "expecting a few {:?}", item);
+///
+/// // Returning the bindings
+/// let name = assert_some_matches!(item, Item { name, qty } if qty > 0 => name);
+/// assert_eq!(name, "apple");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let maybe: Option<i32> = Some(0);
+///
+/// assert_some_matches!(maybe, 1..=5);  // Will panic
+/// # }
+/// ```
+///
+/// [`Some(T)`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_some_matches!`]: ./macro.debug_assert_some_matches.html
+#[macro_export]
+macro_rules! assert_some_matches {
+    (@some $expression:expr, $variants:tt, $result:tt, [$($arg:tt)*]) => {
+        match $expression {
+            Some(value) => $crate::assert_matches!(@unwrapped "Some", value, $variants, $result, [$($arg)*]),
+            None => {
+                $crate::assert_matches!(@panic ["assertion failed, expected Some(..), got None"] [$($arg)*])
+            }
+        }
+    };
+
+    ($expression:expr, $( $pattern:pat )|+) => {
+        $crate::assert_some_matches!(@some $expression, [$($pattern)|+], [()], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr) => {
+        $crate::assert_some_matches!(@some $expression, [$($pattern)|+ if $guard], [()], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+, $($arg:tt)+) => {
+        $crate::assert_some_matches!(@some $expression, [$($pattern)|+], [()], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr, $($arg:tt)+) => {
+        $crate::assert_some_matches!(@some $expression, [$($pattern)|+ if $guard], [()], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ => $result:expr) => {
+        $crate::assert_some_matches!(@some $expression, [$($pattern)|+], [$result], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr => $result:expr) => {
+        $crate::assert_some_matches!(@some $expression, [$($pattern)|+ if $guard], [$result], [])
+    };
+    ($expression:expr, $( $pattern:pat )|+ => $result:expr, $($arg:tt)+) => {
+        $crate::assert_some_matches!(@some $expression, [$($pattern)|+], [$result], [$($arg)+])
+    };
+    ($expression:expr, $( $pattern:pat )|+ if $guard:expr => $result:expr, $($arg:tt)+) => {
+        $crate::assert_some_matches!(@some $expression, [$($pattern)|+ if $guard], [$result], [$($arg)+])
+    };
+}
+
+/// Asserts that expression returns [`Some(T)`] variant, and that `T` matches any of the given variants
+/// in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_some_matches!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Some(T)`]: https://doc.rust-lang.org/core/option/enum.Option.html#variant.Some
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_some_matches!`]: ./macro.assert_some_matches.html
+#[macro_export]
+macro_rules! debug_assert_some_matches {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_some_matches!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    struct Item {
+        name: &'static str,
+        qty: u32,
+    }
+
+    #[test]
+    fn returns_bindings() {
+        let item = Some(Item {
+            name: "apple",
+            qty: 3,
+        });
+        let (name, qty) = assert_some_matches!(item, Item { name, qty } if qty > 0 => (name, qty));
+        assert_eq!((name, qty), ("apple", 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed, expected Some(..), got None: checking apple")]
+    fn wrong_variant() {
+        assert_some_matches!(
+            None::<Item>,
+            Item { qty: 1..=5, .. },
+            "checking {}",
+            "apple"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed, Some value does not match any of the given variants.
+    value: Item { name: "apple", qty: 7 }
+    variants: Item { name: "pear", .. }"#
+    )]
+    fn pattern_mismatch() {
+        assert_some_matches!(
+            Some(Item {
+                name: "apple",
+                qty: 7
+            }),
+            Item { name: "pear", .. }
+        );
+    }
+}
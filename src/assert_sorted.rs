@@ -0,0 +1,160 @@
+/// Asserts that the values of an iterator are sorted in ascending order.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Accepts any [`IntoIterator`], such as slices, vectors or iterators,
+/// and requires its items to implement [`PartialOrd`] and [`Debug`].
+/// Incomparable values, such as `NaN`, are never considered to be sorted.
+///
+/// The order can be changed by passing one of the `<`, `<=`, `>` or `>=` operators
+/// after the values, which every value must satisfy with the next one:
+///
+/// * `assert_sorted!(values)` or `assert_sorted!(values, <=)` checks ascending order,
+/// * `assert_sorted!(values, <)` checks strictly ascending order,
+/// * `assert_sorted!(values, >=)` checks descending order,
+/// * `assert_sorted!(values, >)` checks strictly descending order.
+///
+/// On failure, the index of the first value that is out of order is reported,
+/// along with the value itself and its neighbours. The values are not collected,
+/// so this macro works without an allocator.
+///
+/// See [`assert_sorted_by!`] and [`assert_sorted_by_key!`] for checking the order
+/// by a comparator or a key.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_sorted!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sorted!(&[1, 2, 2, 3]);
+/// assert_sorted!("abc".chars(), <);
+/// assert_sorted!((0..5).rev(), >=);
+///
+/// // With custom messages
+/// assert_sorted!(&[3, 2, 1], >, "Expecting a {}", "countdown");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sorted!(&[1, 3, 2, 4]);  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_sorted_by!`]: ./macro.assert_sorted_by.html
+/// [`assert_sorted_by_key!`]: ./macro.assert_sorted_by_key.html
+/// [`debug_assert_sorted!`]: ./macro.debug_assert_sorted.html
+#[macro_export]
+macro_rules! assert_sorted {
+    (@check $order:ident, $values:expr) => {
+        if let Err(unsorted) = $crate::__private::check_sorted($values, $crate::__private::Order::$order) {
+            panic!("assertion failed: `({} is sorted)`, {}", stringify!($values), unsorted);
+        }
+    };
+    (@check $order:ident, $values:expr,) => {
+        $crate::assert_sorted!(@check $order, $values);
+    };
+    (@check $order:ident, $values:expr, $($arg:tt)+) => {
+        if let Err(unsorted) = $crate::__private::check_sorted($values, $crate::__private::Order::$order) {
+            panic!("assertion failed: `({} is sorted)`, {}: {}", stringify!($values), unsorted, format_args!($($arg)+));
+        }
+    };
+    ($values:expr, < $($rest:tt)*) => {
+        $crate::assert_sorted!(@check StrictlyAscending, $values $($rest)*);
+    };
+    ($values:expr, <= $($rest:tt)*) => {
+        $crate::assert_sorted!(@check Ascending, $values $($rest)*);
+    };
+    ($values:expr, > $($rest:tt)*) => {
+        $crate::assert_sorted!(@check StrictlyDescending, $values $($rest)*);
+    };
+    ($values:expr, >= $($rest:tt)*) => {
+        $crate::assert_sorted!(@check Descending, $values $($rest)*);
+    };
+    ($values:expr) => {
+        $crate::assert_sorted!(@check Ascending, $values);
+    };
+    ($values:expr, $($arg:tt)*) => {
+        $crate::assert_sorted!(@check Ascending, $values, $($arg)*);
+    };
+}
+
+/// Asserts that the values of an iterator are sorted in ascending order in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_sorted!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_sorted!`]: ./macro.assert_sorted.html
+#[macro_export]
+macro_rules! debug_assert_sorted {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_sorted!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(&[1, 3, 5, 4, 6] is sorted)`, element at index `3` is not in ascending order
+    [1]: `3`,
+    [2]: `5`,
+    [3]: `4`,
+    [4]: `6`"#
+    )]
+    fn default_panic_message() {
+        assert_sorted!(&[1, 3, 5, 4, 6]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(&[2, 2] is sorted)`, element at index `1` is not in strictly ascending order
+    [0]: `2`,
+    [1]: `2`: checking 2"#
+    )]
+    fn custom_panic_message() {
+        assert_sorted!(&[2, 2], <, "checking {}", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "element at index `1` is not in descending order")]
+    fn incomparable_values() {
+        assert_sorted!(&[1.0, "NaN".parse::<f64>().unwrap()], >=);
+    }
+
+    #[test]
+    fn sorted() {
+        assert_sorted!(core::iter::empty::<i32>());
+        assert_sorted!(&[1]);
+        assert_sorted!(&[3, 2, 1], >);
+        assert_sorted!("cba".bytes(), >=,);
+    }
+
+    #[test]
+    fn values_named_like_the_order() {
+        let descending = [3, 2, 1];
+        assert_sorted!(descending.iter(), >);
+    }
+}
@@ -0,0 +1,126 @@
+/// Asserts that the values of an iterator are sorted in ascending order by a comparator.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Accepts any [`IntoIterator`], such as slices, vectors or iterators, and a comparator
+/// taking references to two items and returning an [`Ordering`], like [`slice::sort_by`] does.
+/// Requires the items to implement [`Debug`].
+///
+/// Like [`assert_sorted!`], the order can be changed by passing one of the `<`, `<=`, `>`
+/// or `>=` operators after the comparator, e.g. `assert_sorted_by!(values, compare, >)`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_sorted_by!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sorted_by!(&["a", "B", "c"], |a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+/// assert_sorted_by!(&[3, 2, 1], |a, b| a.cmp(b), >);
+///
+/// // With custom messages
+/// assert_sorted_by!(&[1i32, -2, 3], |a, b| a.abs().cmp(&b.abs()), "Expecting {} order", "absolute");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sorted_by!(&[1, 3, 2], |a, b| a.cmp(b));  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`Ordering`]: https://doc.rust-lang.org/core/cmp/enum.Ordering.html
+/// [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_sorted!`]: ./macro.assert_sorted.html
+/// [`debug_assert_sorted_by!`]: ./macro.debug_assert_sorted_by.html
+#[macro_export]
+macro_rules! assert_sorted_by {
+    (@check $order:ident, $values:expr, $compare:expr) => {
+        if let Err(unsorted) = $crate::__private::check_sorted_by($values, $crate::__private::Order::$order, $compare) {
+            panic!("assertion failed: `({} is sorted)`, {}", stringify!($values), unsorted);
+        }
+    };
+    (@check $order:ident, $values:expr, $compare:expr,) => {
+        $crate::assert_sorted_by!(@check $order, $values, $compare);
+    };
+    (@check $order:ident, $values:expr, $compare:expr, $($arg:tt)+) => {
+        if let Err(unsorted) = $crate::__private::check_sorted_by($values, $crate::__private::Order::$order, $compare) {
+            panic!("assertion failed: `({} is sorted)`, {}: {}", stringify!($values), unsorted, format_args!($($arg)+));
+        }
+    };
+    ($values:expr, $compare:expr, < $($rest:tt)*) => {
+        $crate::assert_sorted_by!(@check StrictlyAscending, $values, $compare $($rest)*);
+    };
+    ($values:expr, $compare:expr, <= $($rest:tt)*) => {
+        $crate::assert_sorted_by!(@check Ascending, $values, $compare $($rest)*);
+    };
+    ($values:expr, $compare:expr, > $($rest:tt)*) => {
+        $crate::assert_sorted_by!(@check StrictlyDescending, $values, $compare $($rest)*);
+    };
+    ($values:expr, $compare:expr, >= $($rest:tt)*) => {
+        $crate::assert_sorted_by!(@check Descending, $values, $compare $($rest)*);
+    };
+    ($values:expr, $compare:expr) => {
+        $crate::assert_sorted_by!(@check Ascending, $values, $compare);
+    };
+    ($values:expr, $compare:expr, $($arg:tt)*) => {
+        $crate::assert_sorted_by!(@check Ascending, $values, $compare, $($arg)*);
+    };
+}
+
+/// Asserts that the values of an iterator are sorted in ascending order by a comparator in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_sorted_by!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_sorted_by!`]: ./macro.assert_sorted_by.html
+#[macro_export]
+macro_rules! debug_assert_sorted_by {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_sorted_by!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(&["ccc", "bb", "c", "d"] is sorted)`, element at index `3` is not in strictly descending order
+    [1]: `"bb"`,
+    [2]: `"c"`,
+    [3]: `"d"`"#
+    )]
+    fn default_panic_message() {
+        assert_sorted_by!(&["ccc", "bb", "c", "d"], |a, b| a.len().cmp(&b.len()), >);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(&[1, 0] is sorted)`, element at index `1` is not in ascending order
+    [0]: `1`,
+    [1]: `0`: checking 1"#
+    )]
+    fn custom_panic_message() {
+        assert_sorted_by!(&[1, 0], |a: &&i32, b: &&i32| a.cmp(b), "checking {}", 1);
+    }
+}
@@ -0,0 +1,126 @@
+/// Asserts that the values of an iterator are sorted in ascending order by a key.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Accepts any [`IntoIterator`], such as slices, vectors or iterators, and a function
+/// taking a reference to an item and returning its key, like [`slice::sort_by_key`] does.
+/// Requires the keys to implement [`PartialOrd`], and the items to implement [`Debug`].
+///
+/// Like [`assert_sorted!`], the order can be changed by passing one of the `<`, `<=`, `>`
+/// or `>=` operators after the key function, e.g. `assert_sorted_by_key!(values, key, >)`.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_sorted_by_key!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sorted_by_key!(&["c", "ab", "abc"], |s| s.len());
+/// assert_sorted_by_key!(&[(1, 'c'), (2, 'b'), (3, 'a')], |pair| pair.1, >);
+///
+/// // With custom messages
+/// assert_sorted_by_key!(&[1i32, -2, 3], |x| x.abs(), "Expecting {} order", "absolute");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// assert_sorted_by_key!(&["ab", "c"], |s| s.len());  // Will panic
+/// # }
+/// ```
+///
+/// [`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+/// [`slice::sort_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_key
+/// [`PartialOrd`]: https://doc.rust-lang.org/core/cmp/trait.PartialOrd.html
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`assert_sorted!`]: ./macro.assert_sorted.html
+/// [`debug_assert_sorted_by_key!`]: ./macro.debug_assert_sorted_by_key.html
+#[macro_export]
+macro_rules! assert_sorted_by_key {
+    (@check $order:ident, $values:expr, $key:expr) => {
+        if let Err(unsorted) = $crate::__private::check_sorted_by_key($values, $crate::__private::Order::$order, $key) {
+            panic!("assertion failed: `({} is sorted)`, {}", stringify!($values), unsorted);
+        }
+    };
+    (@check $order:ident, $values:expr, $key:expr,) => {
+        $crate::assert_sorted_by_key!(@check $order, $values, $key);
+    };
+    (@check $order:ident, $values:expr, $key:expr, $($arg:tt)+) => {
+        if let Err(unsorted) = $crate::__private::check_sorted_by_key($values, $crate::__private::Order::$order, $key) {
+            panic!("assertion failed: `({} is sorted)`, {}: {}", stringify!($values), unsorted, format_args!($($arg)+));
+        }
+    };
+    ($values:expr, $key:expr, < $($rest:tt)*) => {
+        $crate::assert_sorted_by_key!(@check StrictlyAscending, $values, $key $($rest)*);
+    };
+    ($values:expr, $key:expr, <= $($rest:tt)*) => {
+        $crate::assert_sorted_by_key!(@check Ascending, $values, $key $($rest)*);
+    };
+    ($values:expr, $key:expr, > $($rest:tt)*) => {
+        $crate::assert_sorted_by_key!(@check StrictlyDescending, $values, $key $($rest)*);
+    };
+    ($values:expr, $key:expr, >= $($rest:tt)*) => {
+        $crate::assert_sorted_by_key!(@check Descending, $values, $key $($rest)*);
+    };
+    ($values:expr, $key:expr) => {
+        $crate::assert_sorted_by_key!(@check Ascending, $values, $key);
+    };
+    ($values:expr, $key:expr, $($arg:tt)*) => {
+        $crate::assert_sorted_by_key!(@check Ascending, $values, $key, $($arg)*);
+    };
+}
+
+/// Asserts that the values of an iterator are sorted in ascending order by a key in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_sorted_by_key!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_sorted_by_key!`]: ./macro.assert_sorted_by_key.html
+#[macro_export]
+macro_rules! debug_assert_sorted_by_key {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_sorted_by_key!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(&[-1, 2, -3, 0] is sorted)`, element at index `3` is not in ascending order
+    [1]: `2`,
+    [2]: `-3`,
+    [3]: `0`"#
+    )]
+    fn default_panic_message() {
+        assert_sorted_by_key!(&[-1, 2, -3, 0], |x: &&i32| x.abs());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"assertion failed: `(&[1, 2] is sorted)`, element at index `1` is not in descending order
+    [0]: `1`,
+    [1]: `2`: checking 1"#
+    )]
+    fn custom_panic_message() {
+        assert_sorted_by_key!(&[1, 2], |x: &&i32| **x, >=, "checking {}", 1);
+    }
+}
@@ -0,0 +1,274 @@
+/// Asserts that the given fields of a struct meet their expectations.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// The expectations are written like a struct pattern, `Name { field: expectation, .., .. }`,
+/// where every expectation is one of:
+///
+/// * `field: pattern`, the field must match the pattern, like in [`assert_matches!`],
+/// * `field <op> value`, optionally with method calls or nested fields after the field name,
+///   e.g. `headers.len() > 2`, the field must compare to the value with the given operator,
+///   one of `==`, `!=`, `<`, `<=`, `>` and `>=`.
+///
+/// The trailing `..` is required, as only the given fields are checked. The expression
+/// must be of the named struct type, and it is only borrowed.
+///
+/// All expectations are checked before panicking, and the panic message lists
+/// every field that does not meet its expectation, with its actual value.
+///
+/// Expectations are split on the top-level comparison operators, so method calls
+/// with turbofish generics need to be wrapped in parentheses.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_struct!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// #[derive(Debug)]
+/// struct Response {
+///     status: u16,
+///     headers: Vec<(String, String)>,
+///     body: Option<String>,
+/// }
+///
+/// # fn main() {
+/// let response = Response {
+///     status: 200,
+///     headers: vec![("Content-Type".into(), "text/plain".into())],
+///     body: Some("Hello".into()),
+/// };
+///
+/// assert_struct!(response, Response { status: 200 | 204, headers.len() >= 1, body: Some(_), .. });
+///
+/// // With custom messages
+/// assert_struct!(response, Response { status < 300, .. }, "expecting {}", "success");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # #[derive(Debug)]
+/// # struct Response {
+/// #     status: u16,
+/// #     body: Option<String>,
+/// # }
+/// # fn main() {
+/// let response = Response { status: 404, body: None };
+///
+/// assert_struct!(response, Response { status: 200, body: Some(_), .. });  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_struct!`]: ./macro.debug_assert_struct.html
+#[macro_export]
+macro_rules! assert_struct {
+    // Splits the expectations on top-level commas, up to the trailing `..`.
+    (@split $value:ident $msg:tt [$($expectations:tt)*] [$($expectation:tt)+] , $($rest:tt)*) => {
+        $crate::assert_struct!(@split $value $msg [$($expectations)* [$($expectation)+]] [] $($rest)*)
+    };
+    (@split $value:ident $msg:tt $expectations:tt [] ..) => {
+        $crate::assert_struct!(@check $value $msg [] $expectations)
+    };
+    (@split $value:ident $msg:tt $expectations:tt [$($expectation:tt)*]) => {
+        compile_error!("assert_struct! requires a trailing `..`, as only the given fields are checked")
+    };
+    (@split $value:ident $msg:tt $expectations:tt [$($expectation:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_struct!(@split $value $msg $expectations [$($expectation)* $next] $($rest)*)
+    };
+
+    // Checks every expectation, binding the actual values so that they outlive the mismatches.
+    (@check $value:ident $msg:tt [$($mismatches:tt)*] [[$field:tt : $( $pattern:pat )|+] $($rest:tt)*]) => {
+        match (&$value.$field, $crate::__debug_value!($value.$field)) {
+            (actual, actual_debug) => $crate::assert_struct!(@check $value $msg [$($mismatches)* (
+                match actual {
+                    $( $pattern )|+ => None,
+                    _ => Some($crate::__private::FieldMismatch {
+                        field: stringify!($field),
+                        expected: $crate::__private::Expected::Pattern(stringify!($($pattern) |+)),
+                        actual: &actual_debug,
+                    }),
+                }
+            )] [$($rest)*]),
+        }
+    };
+    (@check $value:ident $msg:tt $mismatches:tt [[$($expectation:tt)+] $($rest:tt)*]) => {
+        $crate::assert_struct!(@compare $value $msg $mismatches [] [$($expectation)+] [$($rest)*])
+    };
+    (@check $value:ident $msg:tt [] []) => {
+        ()
+    };
+    (@check $value:ident () [$($mismatch:tt)+] []) => {{
+        let mismatches = [$($mismatch),+];
+        let mismatches = $crate::__private::FieldMismatches(&mismatches);
+        if !mismatches.is_empty() {
+            panic!(r#"assertion failed, fields of expression do not match.
+    expression: {:?}{}"#, $crate::__debug_value!($value), mismatches);
+        }
+    }};
+    (@check $value:ident ($($arg:tt)+) [$($mismatch:tt)+] []) => {{
+        let mismatches = [$($mismatch),+];
+        let mismatches = $crate::__private::FieldMismatches(&mismatches);
+        if !mismatches.is_empty() {
+            panic!(r#"assertion failed, fields of expression do not match.
+    expression: {:?}{}: {}"#, $crate::__debug_value!($value), mismatches, format_args!($($arg)+));
+        }
+    }};
+
+    // Splits a comparison on its operator.
+    (@compare $value:ident $msg:tt $mismatches:tt [$($field:tt)+] [== $($expected:tt)+] $rest:tt) => {
+        $crate::assert_struct!(@operator $value $msg $mismatches [$($field)+] == [$($expected)+] $rest)
+    };
+    (@compare $value:ident $msg:tt $mismatches:tt [$($field:tt)+] [!= $($expected:tt)+] $rest:tt) => {
+        $crate::assert_struct!(@operator $value $msg $mismatches [$($field)+] != [$($expected)+] $rest)
+    };
+    (@compare $value:ident $msg:tt $mismatches:tt [$($field:tt)+] [< $($expected:tt)+] $rest:tt) => {
+        $crate::assert_struct!(@operator $value $msg $mismatches [$($field)+] < [$($expected)+] $rest)
+    };
+    (@compare $value:ident $msg:tt $mismatches:tt [$($field:tt)+] [<= $($expected:tt)+] $rest:tt) => {
+        $crate::assert_struct!(@operator $value $msg $mismatches [$($field)+] <= [$($expected)+] $rest)
+    };
+    (@compare $value:ident $msg:tt $mismatches:tt [$($field:tt)+] [> $($expected:tt)+] $rest:tt) => {
+        $crate::assert_struct!(@operator $value $msg $mismatches [$($field)+] > [$($expected)+] $rest)
+    };
+    (@compare $value:ident $msg:tt $mismatches:tt [$($field:tt)+] [>= $($expected:tt)+] $rest:tt) => {
+        $crate::assert_struct!(@operator $value $msg $mismatches [$($field)+] >= [$($expected)+] $rest)
+    };
+    (@compare $value:ident $msg:tt $mismatches:tt [$($field:tt)*] [$next:tt $($tokens:tt)*] $rest:tt) => {
+        $crate::assert_struct!(@compare $value $msg $mismatches [$($field)* $next] [$($tokens)*] $rest)
+    };
+    (@compare $value:ident $msg:tt $mismatches:tt $field:tt [] $rest:tt) => {
+        compile_error!("assert_struct! expects either `field: pattern` or `field <op> value`, with one of the `==`, `!=`, `<`, `<=`, `>` and `>=` operators")
+    };
+    (@operator $value:ident $msg:tt [$($mismatches:tt)*] [$($field:tt)+] $op:tt [$($expected:tt)+] [$($rest:tt)*]) => {
+        match (&$value.$($field)+, &($($expected)+)) {
+            (actual, expected) => match ($crate::__debug_value!(*actual), $crate::__debug_value!(*expected)) {
+                (actual_debug, expected_debug) => $crate::assert_struct!(@check $value $msg [$($mismatches)* (
+                    if *actual $op *expected {
+                        None
+                    } else {
+                        Some($crate::__private::FieldMismatch {
+                            field: stringify!($($field)+),
+                            expected: $crate::__private::Expected::Comparison(stringify!($op), &expected_debug),
+                            actual: &actual_debug,
+                        })
+                    }
+                )] [$($rest)*]),
+            },
+        }
+    };
+
+    ($expression:expr, $name:path { $($expectations:tt)* }) => {
+        match &$expression {
+            value => {
+                let &$name { .. } = value;
+                $crate::assert_struct!(@split value () [] [] $($expectations)*)
+            }
+        }
+    };
+    ($expression:expr, $name:path { $($expectations:tt)* },) => {
+        $crate::assert_struct!($expression, $name { $($expectations)* })
+    };
+    ($expression:expr, $name:path { $($expectations:tt)* }, $($arg:tt)+) => {
+        match &$expression {
+            value => {
+                let &$name { .. } = value;
+                $crate::assert_struct!(@split value ($($arg)+) [] [] $($expectations)*)
+            }
+        }
+    };
+}
+
+/// Asserts that the given fields of a struct meet their expectations in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_struct!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_struct!`]: ./macro.assert_struct.html
+#[macro_export]
+macro_rules! debug_assert_struct {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_struct!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    struct Response {
+        status: u16,
+        headers: [&'static str; 2],
+        body: Option<&'static str>,
+    }
+
+    const NOT_FOUND: Response = Response {
+        status: 404,
+        headers: ["Server", "Date"],
+        body: None,
+    };
+
+    #[test]
+    fn fields_match() {
+        assert_struct!(NOT_FOUND, Response { .. });
+        assert_struct!(
+            NOT_FOUND,
+            Response {
+                status: 400..=499,
+                headers.len() == 2,
+                headers[0].len() > 3,
+                body: None,
+                ..
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed, fields of expression do not match.
+    expression: Response { status: 404, headers: ["Server", "Date"], body: None }
+    status: expected `200 | 204`, got `404`,
+    body: expected `Some(_)`, got `None`"#)]
+    fn default_panic_message() {
+        assert_struct!(
+            NOT_FOUND,
+            Response {
+                status: 200 | 204,
+                body: Some(_),
+                ..
+            }
+        );
+    }
+
+    // The field is not part of the expected message, as older compilers stringify
+    // `headers.len()` with spaces between its tokens.
+    #[test]
+    #[should_panic(expected = r#": expected `> 2`, got `2`,
+    body: expected `Some(_)`, got `None`"#)]
+    fn method_call_panic_message() {
+        assert_struct!(NOT_FOUND, Response { headers.len() > 2, body: Some(_), .. });
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed, fields of expression do not match.
+    expression: Response { status: 404, headers: ["Server", "Date"], body: None }
+    status: expected `< 400`, got `404`: checking 404"#)]
+    fn custom_panic_message() {
+        assert_struct!(NOT_FOUND, Response { status < 400, body: None, .. }, "checking {}", 404);
+    }
+}
@@ -0,0 +1,142 @@
+/// Asserts that expression is the given enum variant, whatever its fields are.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// The variant is given by its path, such as `MyEnum::Foo`, and can be a unit,
+/// tuple or struct variant. Unlike [`assert_matches!`], no placeholders for the fields
+/// (`MyEnum::Foo(..)` or `MyEnum::Foo { .. }`) are required.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_variant!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// #[derive(Debug)]
+/// enum Shape {
+///     Point,
+///     Circle(f64),
+///     Rect { width: f64, height: f64 },
+/// }
+///
+/// # fn main() {
+/// assert_variant!(Shape::Point, Shape::Point);
+/// assert_variant!(Shape::Circle(1.0), Shape::Circle);
+/// assert_variant!(Shape::Rect { width: 1.0, height: 2.0 }, Shape::Rect);
+///
+/// // With custom messages
+/// assert_variant!(Some(1), Option::Some, "expecting {}", "something");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # fn main() {
+/// let res: Result<i32, ()> = Err(());
+/// assert_variant!(res, Result::Ok);  // Will panic
+/// # }
+/// ```
+///
+/// [`assert_matches!`]: ./macro.assert_matches.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_variant!`]: ./macro.debug_assert_variant.html
+#[macro_export]
+macro_rules! assert_variant {
+    ($expression:expr, $variant:path) => {
+        match $expression {
+            $variant { .. } => {},
+            ref other => {
+                panic!(r#"assertion failed, expression is not the given variant.
+    expression: {:?}
+    variant: {}"#, $crate::__debug_value!(other), stringify!($variant));
+            }
+        }
+    };
+    ($expression:expr, $variant:path,) => {
+        $crate::assert_variant!($expression, $variant);
+    };
+    ($expression:expr, $variant:path, $($arg:tt)+) => {
+        match $expression {
+            $variant { .. } => {},
+            ref other => {
+                panic!(r#"assertion failed, expression is not the given variant.
+    expression: {:?}
+    variant: {}: {}"#, $crate::__debug_value!(other), stringify!($variant), format_args!($($arg)+));
+            }
+        }
+    };
+}
+
+/// Asserts that expression is the given enum variant, whatever its fields are, in runtime.
+///
+/// This macro is available for Rust 1.30+.
+///
+/// Like [`assert_variant!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_variant!`]: ./macro.assert_variant.html
+#[macro_export]
+macro_rules! debug_assert_variant {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_variant!($($arg)*); })
+}
+
+#[cfg(test)]
+#[cfg(not(has_private_in_public_issue))]
+mod tests {
+    #[derive(Debug)]
+    enum Error {
+        Timeout,
+        Io(&'static str),
+        Status { code: u16 },
+    }
+
+    #[test]
+    fn matches_any_kind_of_variant() {
+        let errors = [
+            Error::Timeout,
+            Error::Io("reset"),
+            Error::Status { code: 500 },
+        ];
+        assert_variant!(errors[0], Error::Timeout);
+        assert_variant!(errors[1], Error::Io);
+        assert_variant!(&errors[2], Error::Status);
+
+        // The fields are left to a pattern match.
+        if let Error::Io(reason) = errors[1] {
+            assert_eq!(reason, "reset");
+        }
+        if let Error::Status { code } = errors[2] {
+            assert_eq!(code, 500);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed, expression is not the given variant.
+    expression: Status { code: 404 }
+    variant: Error::Io"#)]
+    fn default_panic_message() {
+        assert_variant!(Error::Status { code: 404 }, Error::Io);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"assertion failed, expression is not the given variant.
+    expression: Io("reset")
+    variant: Error::Timeout: checking reset"#)]
+    fn custom_panic_message() {
+        assert_variant!(Error::Io("reset"), Error::Timeout, "checking {}", "reset");
+    }
+}
@@ -0,0 +1,84 @@
+/// Asserts that expression returns [`Err(E)`] variant, whose [`io::Error`] is of
+/// [`ErrorKind::WouldBlock`] kind.
+///
+/// This macro is available for Rust 1.30+, with the `std` feature enabled.
+///
+/// It is a shorthand for [`assert_io_err_kind!`] with [`ErrorKind::WouldBlock`],
+/// and returns the error the same way.
+///
+/// ## Uses
+///
+/// Assertions are always checked in both debug and release builds, and cannot be disabled.
+/// See [`debug_assert_would_block!`] for assertions that are not enabled in release builds by default.
+///
+/// ## Custom messages
+///
+/// This macro has a second form, where a custom panic message can be provided
+/// with or without arguments for formatting. See [`std::fmt`] for syntax for this form.
+///
+/// ## Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate claims;
+/// use std::io::{self, ErrorKind};
+///
+/// fn read() -> io::Result<usize> {
+///     Err(io::Error::new(ErrorKind::WouldBlock, "operation would block"))
+/// }
+///
+/// # fn main() {
+/// assert_would_block!(read());
+///
+/// // With custom messages
+/// assert_would_block!(read(), "expecting the read to {}", "fail");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate claims;
+/// # use std::io;
+/// # fn main() {
+/// let res: io::Result<usize> = Ok(42);
+///
+/// assert_would_block!(res);  // Will panic
+/// # }
+/// ```
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+/// [`ErrorKind::WouldBlock`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.WouldBlock
+/// [`assert_io_err_kind!`]: ./macro.assert_io_err_kind.html
+/// [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
+/// [`debug_assert_would_block!`]: ./macro.debug_assert_would_block.html
+#[macro_export]
+macro_rules! assert_would_block {
+    ($cond:expr) => {
+        $crate::assert_io_err_kind!($cond, $crate::__private::ErrorKind::WouldBlock)
+    };
+    ($cond:expr, $($arg:tt)*) => {
+        $crate::assert_io_err_kind!($cond, $crate::__private::ErrorKind::WouldBlock, $($arg)*)
+    };
+}
+
+/// Asserts that expression returns [`Err(E)`] variant, whose [`io::Error`] is of
+/// [`ErrorKind::WouldBlock`] kind in runtime.
+///
+/// This macro is available for Rust 1.30+, with the `std` feature enabled.
+///
+/// Like [`assert_would_block!`], this macro also has a second version,
+/// where a custom panic message can be provided.
+///
+/// ## Uses
+///
+/// See [`debug_assert!`] documentation for possible use cases.
+/// The same applies to this macro.
+///
+/// [`Err(E)`]: https://doc.rust-lang.org/core/result/enum.Result.html#variant.Err
+/// [`io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+/// [`ErrorKind::WouldBlock`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.WouldBlock
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+/// [`assert_would_block!`]: ./macro.assert_would_block.html
+#[macro_export]
+macro_rules! debug_assert_would_block {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_would_block!($($arg)*); })
+}
@@ -0,0 +1,63 @@
+use core::fmt::{self, Write};
+
+/// Searches the text written to it for the needle, a byte at a time, so that the text
+/// never needs to be stored.
+struct Matcher<'a> {
+    needle: &'a [u8],
+    matched: usize,
+}
+
+impl<'a> Matcher<'a> {
+    /// Returns the length of the longest prefix of the needle that the text ends with,
+    /// once `byte` is appended to it.
+    fn advance(&self, byte: u8) -> usize {
+        // The text written so far ends with the first `matched` bytes of the needle, so the
+        // candidates are all compared against the needle itself.
+        let mut length = self.matched + 1;
+        while length > 0 {
+            let start = self.matched + 1 - length;
+            if self.needle[length - 1] == byte
+                && self.needle[..length - 1] == self.needle[start..self.matched]
+            {
+                return length;
+            }
+            length -= 1;
+        }
+        0
+    }
+}
+
+impl<'a> Write for Matcher<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // `Display` implementations may ignore the error and keep writing.
+        if self.matched == self.needle.len() {
+            return Err(fmt::Error);
+        }
+        for &byte in s.as_bytes() {
+            self.matched = self.advance(byte);
+            if self.matched == self.needle.len() {
+                // Stops the formatting, as the rest of the text does not matter anymore.
+                return Err(fmt::Error);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks whether the [`Display`] output of `value` contains `needle`, without allocating.
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+#[doc(hidden)]
+pub fn display_contains<T: fmt::Display + ?Sized>(value: &T, needle: &str) -> bool {
+    if needle.is_empty() {
+        return true;
+    }
+    let mut matcher = Matcher {
+        needle: needle.as_bytes(),
+        matched: 0,
+    };
+    // An error is returned either when the needle is found, or by the `Display` implementation
+    // itself, in which case the text written up to that point is all there is to search.
+    let _ = write!(matcher, "{}", value);
+    matcher.matched == matcher.needle.len()
+}
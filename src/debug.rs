@@ -0,0 +1,72 @@
+use core::fmt;
+
+/// Value formatted in the failure messages of the macros, which may or may not implement
+/// [`Debug`].
+///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct MaybeDebug<'a, T: ?Sized>(pub &'a T);
+
+/// Placeholder printed in place of values that do not implement [`Debug`].
+///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+#[doc(hidden)]
+pub struct NoDebug(&'static str);
+
+impl fmt::Debug for NoDebug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}: no Debug impl>", self.0)
+    }
+}
+
+#[cfg(rustc_1_38)]
+fn type_name<T: ?Sized>() -> &'static str {
+    core::any::type_name::<T>()
+}
+
+// `core::any::type_name` is not available before Rust 1.38.
+#[cfg(not(rustc_1_38))]
+fn type_name<T: ?Sized>() -> &'static str {
+    "_"
+}
+
+/// Picks how values are formatted in the failure messages of the macros.
+///
+/// Values implementing [`Debug`] resolve to the impl on `MaybeDebug` itself, everything else
+/// falls back to the impl on `&MaybeDebug` through autoref, which prints the name of the type.
+///
+/// [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+#[doc(hidden)]
+pub trait DebugValue {
+    type Output: fmt::Debug;
+
+    fn debug_value(&self) -> Self::Output;
+}
+
+impl<'a, T: fmt::Debug + ?Sized> DebugValue for MaybeDebug<'a, T> {
+    type Output = &'a T;
+
+    fn debug_value(&self) -> &'a T {
+        self.0
+    }
+}
+
+impl<'a, 'b, T: ?Sized> DebugValue for &'b MaybeDebug<'a, T> {
+    type Output = NoDebug;
+
+    fn debug_value(&self) -> NoDebug {
+        NoDebug(type_name::<T>())
+    }
+}
+
+/// Borrows the given place for formatting it with `{:?}` in a failure message,
+/// whether it implements `Debug` or not.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_value {
+    ($value:expr) => {{
+        use $crate::__private::DebugValue;
+        (&$crate::__private::MaybeDebug(&$value)).debug_value()
+    }};
+}
@@ -0,0 +1,200 @@
+use core::fmt;
+
+/// Difference between two values, printed by the comparison macros on failure.
+///
+/// Implemented for all built-in integer and floating point types. Implement it for your own
+/// types to have [`assert_lt!`] and friends report how far apart the operands are.
+///
+/// ## Examples
+///
+/// ```rust
+/// # extern crate claims;
+/// use claims::Delta;
+///
+/// #[derive(Debug, PartialEq, PartialOrd)]
+/// struct Millis(u32);
+///
+/// impl Delta for Millis {
+///     type Difference = i64;
+///
+///     fn difference(&self, other: &Self) -> i64 {
+///         i64::from(self.0) - i64::from(other.0)
+///     }
+///
+///     fn relative_difference(&self, other: &Self) -> Option<f64> {
+///         self.0.relative_difference(&other.0)
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// [`assert_lt!`]: ./macro.assert_lt.html
+pub trait Delta {
+    /// Type of the difference between two values.
+    type Difference: fmt::Debug;
+
+    /// Returns the difference `self - other`.
+    fn difference(&self, other: &Self) -> Self::Difference;
+
+    /// Returns the difference relative to `other`, i.e. `(self - other) / |other|`,
+    /// or `None` if it is not meaningful (for example, when `other` is zero).
+    fn relative_difference(&self, other: &Self) -> Option<f64>;
+}
+
+/// Exact difference between two integers of any built-in integer type.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct IntegerDifference {
+    negative: bool,
+    magnitude: u128,
+}
+
+impl fmt::Debug for IntegerDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            tri!(f.write_str("-"));
+        }
+        fmt::Debug::fmt(&self.magnitude, f)
+    }
+}
+
+fn relative(difference: f64, base: f64) -> Option<f64> {
+    if base == 0.0 || base.is_nan() || difference.is_nan() {
+        None
+    } else if base < 0.0 {
+        Some(difference / -base)
+    } else {
+        Some(difference / base)
+    }
+}
+
+fn integer_difference(left: u128, right: u128, less: bool) -> IntegerDifference {
+    // Sign-extended operands of the same width are exactly `magnitude` apart modulo 2^128,
+    // which covers the distance between any two built-in integers.
+    IntegerDifference {
+        negative: less,
+        magnitude: if less {
+            right.wrapping_sub(left)
+        } else {
+            left.wrapping_sub(right)
+        },
+    }
+}
+
+macro_rules! impl_delta_for_integers {
+    ($($int:ident)*) => {
+        $(
+            impl Delta for $int {
+                type Difference = IntegerDifference;
+
+                fn difference(&self, other: &Self) -> IntegerDifference {
+                    integer_difference(*self as u128, *other as u128, self < other)
+                }
+
+                fn relative_difference(&self, other: &Self) -> Option<f64> {
+                    relative(*self as f64 - *other as f64, *other as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_delta_for_integers!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 usize);
+
+impl Delta for u128 {
+    type Difference = IntegerDifference;
+
+    fn difference(&self, other: &Self) -> IntegerDifference {
+        integer_difference(*self, *other, self < other)
+    }
+
+    fn relative_difference(&self, other: &Self) -> Option<f64> {
+        relative(*self as f64 - *other as f64, *other as f64)
+    }
+}
+
+impl Delta for f64 {
+    type Difference = f64;
+
+    fn difference(&self, other: &Self) -> f64 {
+        self - other
+    }
+
+    fn relative_difference(&self, other: &Self) -> Option<f64> {
+        relative(self - other, *other)
+    }
+}
+
+impl Delta for f32 {
+    type Difference = f32;
+
+    fn difference(&self, other: &Self) -> f32 {
+        self - other
+    }
+
+    fn relative_difference(&self, other: &Self) -> Option<f64> {
+        relative(f64::from(*self) - f64::from(*other), f64::from(*other))
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct DeltaOperands<'a, L: ?Sized, R: ?Sized>(pub &'a L, pub &'a R);
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct DeltaLines<'a, T: ?Sized>(&'a T, &'a T);
+
+impl<'a, T: Delta + ?Sized> fmt::Display for DeltaLines<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        tri!(write!(
+            f,
+            ",\n    difference: `{:?}`",
+            self.0.difference(self.1)
+        ));
+        if let Some(relative) = self.0.relative_difference(self.1) {
+            tri!(write!(
+                f,
+                ",\n    relative difference: `{:+.2}%`",
+                relative * 100.0
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Picks the extra failure message lines for the comparison macros.
+///
+/// Operands of the same [`Delta`] type resolve to the impl on `DeltaOperands` itself, everything
+/// else falls back to the impl on `&DeltaOperands` through autoref, which prints nothing.
+#[doc(hidden)]
+pub trait DeltaMessage {
+    type Output: fmt::Display;
+
+    fn delta_message(&self) -> Self::Output;
+}
+
+impl<'a, T: Delta + ?Sized> DeltaMessage for DeltaOperands<'a, T, T> {
+    type Output = DeltaLines<'a, T>;
+
+    fn delta_message(&self) -> DeltaLines<'a, T> {
+        DeltaLines(self.0, self.1)
+    }
+}
+
+impl<'a, 'b, L: ?Sized, R: ?Sized> DeltaMessage for &'b DeltaOperands<'a, L, R> {
+    type Output = &'static str;
+
+    fn delta_message(&self) -> &'static str {
+        ""
+    }
+}
+
+/// Formats the extra failure message lines of the comparison macros for the given places.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __delta_message {
+    ($left:expr, $right:expr) => {{
+        use $crate::__private::DeltaMessage;
+        (&$crate::__private::DeltaOperands(&$left, &$right)).delta_message()
+    }};
+}
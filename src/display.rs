@@ -0,0 +1,50 @@
+use core::fmt;
+
+/// Value whose [`Display`] output is added to a failure message, if it implements [`Display`].
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct MaybeDisplay<T>(pub T);
+
+/// Line listing the [`Display`] output of a value in a failure message.
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct DisplayLine<T>(T);
+
+impl<'a, T: fmt::Display + ?Sized> fmt::Display for DisplayLine<&'a T> {
+    fn fmt<'f>(&self, f: &mut fmt::Formatter<'f>) -> fmt::Result {
+        write!(f, "\n    display: {}", self.0)
+    }
+}
+
+/// Picks whether the [`Display`] output of a value is added to a failure message.
+///
+/// Values implementing [`Display`] resolve to the impl on `MaybeDisplay` itself, everything else
+/// falls back to the impl on `&MaybeDisplay` through autoref, which adds nothing.
+///
+/// [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
+#[doc(hidden)]
+pub trait DisplayValue {
+    type Output: fmt::Display;
+
+    fn display_value(&self) -> Self::Output;
+}
+
+impl<'a, T: fmt::Display + ?Sized> DisplayValue for MaybeDisplay<&'a T> {
+    type Output = DisplayLine<&'a T>;
+
+    fn display_value(&self) -> DisplayLine<&'a T> {
+        DisplayLine(self.0)
+    }
+}
+
+impl<'b, T> DisplayValue for &'b MaybeDisplay<T> {
+    type Output = &'static str;
+
+    fn display_value(&self) -> &'static str {
+        ""
+    }
+}
@@ -0,0 +1,45 @@
+use core::fmt;
+
+/// Elements of an iterator, listed in the failure message of `assert_any_match!`.
+///
+/// The iterator is cloned for every formatting, so that its elements can be listed
+/// after it has already been searched for a match. Every element is formatted by the given
+/// function, which lets the macro pick the formatting for the concrete element type.
+#[doc(hidden)]
+pub struct Elements<I, F>(I, F);
+
+impl<I, F> Elements<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(&I::Item, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    /// Lists the elements of `iter`, formatting each of them with `format`.
+    pub fn new(iter: I, format: F) -> Self {
+        Elements(iter, format)
+    }
+}
+
+impl<I, F> fmt::Debug for Elements<I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(&I::Item, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for element in self.0.clone() {
+            let _ = list.entry(&Element(&element, &self.1));
+        }
+        list.finish()
+    }
+}
+
+struct Element<'a, T, F>(&'a T, &'a F);
+
+impl<'a, T, F> fmt::Debug for Element<'a, T, F>
+where
+    F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(self.0, f)
+    }
+}
@@ -0,0 +1,57 @@
+use core::fmt;
+
+/// Expectation on a field that `assert_struct!` found not to hold.
+#[doc(hidden)]
+#[derive(Debug)]
+pub enum Expected<'a> {
+    /// The field should match the given pattern.
+    Pattern(&'static str),
+    /// The field should compare to the given value with the given operator.
+    Comparison(&'static str, &'a dyn fmt::Debug),
+}
+
+impl<'a> fmt::Display for Expected<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Expected::Pattern(pattern) => f.write_str(pattern),
+            Expected::Comparison(operator, value) => write!(f, "{} {:?}", operator, value),
+        }
+    }
+}
+
+/// Field of a struct that does not meet its expectation in `assert_struct!`.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct FieldMismatch<'a> {
+    pub field: &'static str,
+    pub expected: Expected<'a>,
+    pub actual: &'a dyn fmt::Debug,
+}
+
+/// Failure message lines of `assert_struct!`, one for every field that does not meet
+/// its expectation.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct FieldMismatches<'a>(pub &'a [Option<FieldMismatch<'a>>]);
+
+impl<'a> FieldMismatches<'a> {
+    /// Returns whether every field meets its expectation.
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(Option::is_none)
+    }
+}
+
+impl<'a> fmt::Display for FieldMismatches<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "\n";
+        for mismatch in self.0.iter().filter_map(Option::as_ref) {
+            tri!(write!(
+                f,
+                "{}    {}: expected `{}`, got `{:?}`",
+                separator, mismatch.field, mismatch.expected, mismatch.actual
+            ));
+            separator = ",\n";
+        }
+        Ok(())
+    }
+}
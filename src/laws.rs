@@ -0,0 +1,349 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+#[derive(Clone, Copy)]
+enum Detail {
+    PartialCmp(Option<Ordering>),
+    Cmp(Ordering),
+    Bool(bool),
+    Hash(u64),
+}
+
+impl fmt::Debug for Detail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Detail::PartialCmp(ordering) => fmt::Debug::fmt(&ordering, f),
+            Detail::Cmp(ordering) => fmt::Debug::fmt(&ordering, f),
+            Detail::Bool(value) => fmt::Debug::fmt(&value, f),
+            Detail::Hash(hash) => write!(f, "{:#018x}", hash),
+        }
+    }
+}
+
+/// Smallest counterexample found by the law-checking macros.
+///
+/// Holds the violated law, up to three sample values (with their indices) involved in it,
+/// and the results of the operations on them that contradict each other.
+#[doc(hidden)]
+pub struct LawViolation<'a, T> {
+    law: &'static str,
+    values: [Option<(usize, &'a T)>; 3],
+    details: [Option<(&'static str, Detail)>; 3],
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for LawViolation<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LawViolation")
+            .field("law", &self.law)
+            .field("values", &self.values)
+            .field("details", &self.details)
+            .finish()
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Display for LawViolation<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const NAMES: [&str; 3] = ["a", "b", "c"];
+
+        tri!(f.write_str(self.law));
+        let mut separator = "\n";
+        for (name, value) in NAMES.iter().zip(&self.values) {
+            if let Some((index, value)) = *value {
+                tri!(write!(
+                    f,
+                    "{}    {}: `{:?}` (at index {})",
+                    separator, name, value, index
+                ));
+                separator = ",\n";
+            }
+        }
+        for detail in &self.details {
+            if let Some((label, detail)) = *detail {
+                tri!(write!(f, ",\n    {}: `{:?}`", label, detail));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn ord_pair<'a, T: PartialOrd>(
+    law: &'static str,
+    samples: &'a [T],
+    i: usize,
+    j: usize,
+    cmp: Option<Ordering>,
+) -> LawViolation<'a, T> {
+    let (a, b) = (&samples[i], &samples[j]);
+    LawViolation {
+        law,
+        values: [Some((i, a)), Some((j, b)), None],
+        details: [
+            Some(("a.partial_cmp(b)", Detail::PartialCmp(a.partial_cmp(b)))),
+            Some(("b.partial_cmp(a)", Detail::PartialCmp(b.partial_cmp(a)))),
+            cmp.map(|cmp| ("a.cmp(b)", Detail::Cmp(cmp))),
+        ],
+    }
+}
+
+fn check_ord_pair<T: PartialOrd>(
+    samples: &[T],
+    i: usize,
+    j: usize,
+    cmp: Option<fn(&T, &T) -> Ordering>,
+) -> Result<(), LawViolation<'_, T>> {
+    let (a, b) = (&samples[i], &samples[j]);
+    let ordering = a.partial_cmp(b);
+
+    if let Some(cmp) = cmp {
+        if i == j && cmp(a, a) != Ordering::Equal {
+            let law = "reflexivity, `a.cmp(a)` is not `Equal`";
+            return Err(ord_pair(law, samples, i, j, Some(cmp(a, a))));
+        }
+        if ordering.is_none() {
+            let law = "totality, `a.partial_cmp(b)` is `None` for an `Ord` type";
+            return Err(ord_pair(law, samples, i, j, None));
+        }
+        if ordering != Some(cmp(a, b)) {
+            let law = "consistency, `a.partial_cmp(b)` is not `Some(a.cmp(b))`";
+            return Err(ord_pair(law, samples, i, j, Some(cmp(a, b))));
+        }
+    }
+    if (a == b) != (ordering == Some(Ordering::Equal)) {
+        let law = "consistency with `PartialEq`, `a == b` does not match `a.partial_cmp(b) == Some(Equal)`";
+        return Err(ord_pair(law, samples, i, j, None));
+    }
+    if (a != b) == (a == b) {
+        let law = "consistency with `PartialEq`, `a != b` is not the negation of `a == b`";
+        return Err(ord_pair(law, samples, i, j, None));
+    }
+    if (a < b) != (ordering == Some(Ordering::Less))
+        || (a > b) != (ordering == Some(Ordering::Greater))
+        || (a <= b) != (ordering == Some(Ordering::Less) || ordering == Some(Ordering::Equal))
+        || (a >= b) != (ordering == Some(Ordering::Greater) || ordering == Some(Ordering::Equal))
+    {
+        let law = "consistency, `<`, `<=`, `>` or `>=` do not match `a.partial_cmp(b)`";
+        return Err(ord_pair(law, samples, i, j, None));
+    }
+    if ordering != b.partial_cmp(a).map(Ordering::reverse) {
+        let law = "antisymmetry, `a.partial_cmp(b)` is not the reverse of `b.partial_cmp(a)`";
+        return Err(ord_pair(law, samples, i, j, None));
+    }
+    Ok(())
+}
+
+fn check_ord_triple<T: PartialOrd>(
+    samples: &[T],
+    i: usize,
+    j: usize,
+    k: usize,
+) -> Result<(), LawViolation<'_, T>> {
+    let (a, b, c) = (&samples[i], &samples[j], &samples[k]);
+    let expected = match (a.partial_cmp(b), b.partial_cmp(c)) {
+        (Some(Ordering::Equal), ordering) | (ordering, Some(Ordering::Equal)) => ordering,
+        (Some(Ordering::Less), Some(Ordering::Less)) => Some(Ordering::Less),
+        (Some(Ordering::Greater), Some(Ordering::Greater)) => Some(Ordering::Greater),
+        _ => return Ok(()),
+    };
+    if expected.is_some() && a.partial_cmp(c) != expected {
+        return Err(LawViolation {
+            law: "transitivity, `a.partial_cmp(c)` does not follow from `a.partial_cmp(b)` and `b.partial_cmp(c)`",
+            values: [Some((i, a)), Some((j, b)), Some((k, c))],
+            details: [
+                Some(("a.partial_cmp(b)", Detail::PartialCmp(a.partial_cmp(b)))),
+                Some(("b.partial_cmp(c)", Detail::PartialCmp(b.partial_cmp(c)))),
+                Some(("a.partial_cmp(c)", Detail::PartialCmp(a.partial_cmp(c)))),
+            ],
+        });
+    }
+    Ok(())
+}
+
+// Counterexamples are searched for by increasing size, single values first, then pairs, then
+// triples, each in lexicographic order of their indices, so that the reported one is minimal.
+fn check_laws<'a, T, P, Q>(
+    samples: &'a [T],
+    check_pair: P,
+    check_triple: Q,
+) -> Result<(), LawViolation<'a, T>>
+where
+    P: Fn(usize, usize) -> Result<(), LawViolation<'a, T>>,
+    Q: Fn(usize, usize, usize) -> Result<(), LawViolation<'a, T>>,
+{
+    let len = samples.len();
+    for i in 0..len {
+        tri!(check_pair(i, i));
+    }
+    for i in 0..len {
+        for j in i + 1..len {
+            tri!(check_pair(i, j));
+            tri!(check_pair(j, i));
+        }
+    }
+    for i in 0..len {
+        for j in 0..len {
+            for k in 0..len {
+                tri!(check_triple(i, j, k));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks the `PartialOrd` and `PartialEq` laws for `assert_partial_ord_laws!`.
+#[doc(hidden)]
+pub fn check_partial_ord_laws<T: PartialOrd>(samples: &[T]) -> Result<(), LawViolation<'_, T>> {
+    check_laws(
+        samples,
+        |i, j| check_ord_pair(samples, i, j, None),
+        |i, j, k| check_ord_triple(samples, i, j, k),
+    )
+}
+
+/// Checks the `Ord`, `PartialOrd` and `PartialEq` laws for `assert_ord_laws!`.
+#[doc(hidden)]
+pub fn check_ord_laws<T: Ord>(samples: &[T]) -> Result<(), LawViolation<'_, T>> {
+    let cmp: fn(&T, &T) -> Ordering = T::cmp;
+    check_laws(
+        samples,
+        |i, j| check_ord_pair(samples, i, j, Some(cmp)),
+        |i, j, k| check_ord_triple(samples, i, j, k),
+    )
+}
+
+fn check_eq_pair<T: PartialEq>(
+    samples: &[T],
+    i: usize,
+    j: usize,
+    reflexive: bool,
+) -> Result<(), LawViolation<'_, T>> {
+    let (a, b) = (&samples[i], &samples[j]);
+    let law = if i == j && reflexive && a != b {
+        "reflexivity, `a == a` does not hold for an `Eq` type"
+    } else if a.eq(b) != b.eq(a) {
+        "symmetry, `a == b` does not match `b == a`"
+    } else if (a != b) == (a == b) {
+        "consistency, `a != b` is not the negation of `a == b`"
+    } else {
+        return Ok(());
+    };
+    Err(LawViolation {
+        law,
+        values: [Some((i, a)), Some((j, b)), None],
+        details: [
+            Some(("a == b", Detail::Bool(a == b))),
+            Some(("b == a", Detail::Bool(b == a))),
+            Some(("a != b", Detail::Bool(a != b))),
+        ],
+    })
+}
+
+fn check_eq_triple<T: PartialEq>(
+    samples: &[T],
+    i: usize,
+    j: usize,
+    k: usize,
+) -> Result<(), LawViolation<'_, T>> {
+    let (a, b, c) = (&samples[i], &samples[j], &samples[k]);
+    if a == b && b == c && a != c {
+        return Err(LawViolation {
+            law: "transitivity, `a == b` and `b == c` but not `a == c`",
+            values: [Some((i, a)), Some((j, b)), Some((k, c))],
+            details: [
+                Some(("a == b", Detail::Bool(a == b))),
+                Some(("b == c", Detail::Bool(b == c))),
+                Some(("a == c", Detail::Bool(a == c))),
+            ],
+        });
+    }
+    Ok(())
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct EqSamples<'a, T>(pub &'a [T]);
+
+/// Checks the `PartialEq` laws for `assert_eq_laws!`, including reflexivity for `Eq` samples.
+///
+/// Samples of an [`Eq`] type resolve to the impl on `EqSamples` itself, everything else falls
+/// back to the impl on `&EqSamples` through autoref, which skips reflexivity.
+#[doc(hidden)]
+pub trait CheckEqLaws<'a, T> {
+    fn check_eq_laws(&self) -> Result<(), LawViolation<'a, T>>;
+}
+
+impl<'a, T: Eq> CheckEqLaws<'a, T> for EqSamples<'a, T> {
+    fn check_eq_laws(&self) -> Result<(), LawViolation<'a, T>> {
+        let samples = self.0;
+        check_laws(
+            samples,
+            |i, j| check_eq_pair(samples, i, j, true),
+            |i, j, k| check_eq_triple(samples, i, j, k),
+        )
+    }
+}
+
+impl<'a, T: PartialEq> CheckEqLaws<'a, T> for &EqSamples<'a, T> {
+    fn check_eq_laws(&self) -> Result<(), LawViolation<'a, T>> {
+        let samples = self.0;
+        check_laws(
+            samples,
+            |i, j| check_eq_pair(samples, i, j, false),
+            |i, j, k| check_eq_triple(samples, i, j, k),
+        )
+    }
+}
+
+/// Deterministic 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hasher.
+///
+/// Unlike the randomly seeded hasher of `std`, it is available without `std`
+/// and produces the same hashes on every run, so they can be printed and compared.
+#[derive(Debug, Clone, Copy)]
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = FnvHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Checks that equal samples have equal hashes for `assert_hash_consistent!`.
+#[doc(hidden)]
+pub fn check_hash_consistent<T: Hash + PartialEq>(
+    samples: &[T],
+) -> Result<(), LawViolation<'_, T>> {
+    let check_pair = |i: usize, j: usize| {
+        let (a, b) = (&samples[i], &samples[j]);
+        if a == b && hash(a) != hash(b) {
+            return Err(LawViolation {
+                law: "`a == b` but their hashes differ",
+                values: [Some((i, a)), Some((j, b)), None],
+                details: [
+                    Some(("hash(a)", Detail::Hash(hash(a)))),
+                    Some(("hash(b)", Detail::Hash(hash(b)))),
+                    None,
+                ],
+            });
+        }
+        Ok(())
+    };
+    check_laws(samples, check_pair, |_, _, _| Ok(()))
+}
@@ -29,11 +29,19 @@
 //!
 //! `claims` can be used in a `no-std` environments too.
 //!
+//! Macros that need the standard library, like [`assert_err_source_chain`],
+//! are only available with the `std` feature enabled.
+//!
 //! ## Available macros
 //!
 //! Note that same to [`core`]/[`std`] macros,
 //! all macros in this crate has the [`debug_*`](#macros) counterparts.
 //!
+//! Failure messages print the values involved with their [`Debug`] implementation.
+//! From Rust 1.31 on, values that do not implement it are printed as `<TypeName: no Debug impl>`
+//! instead, except by the macros checking the laws of the comparison traits, sorted order
+//! and monotonicity, which require it.
+//!
 //! ### Comparison
 //!
 //! Assertions similar to [`assert_eq`] or [`assert_ne`]:
@@ -43,9 +51,86 @@
 //! * [`assert_le`]
 //! * [`assert_lt`]
 //!
+//! From Rust 1.31 on, failure messages of these macros include the difference between
+//! the operands for types implementing [`Delta`], such as the built-in numeric types.
+//!
+//! Their strict counterparts, which also fail on unordered values:
+//!
+//! * [`assert_ge_strict`]
+//! * [`assert_gt_strict`]
+//! * [`assert_le_strict`]
+//! * [`assert_lt_strict`]
+//!
+//! Assertions on the [`Ordering`] returned by `partial_cmp` and `cmp`:
+//!
+//! * [`assert_ordering`]
+//! * [`assert_cmp_eq`]
+//! * [`assert_partial_cmp_eq`]
+//!
+//! Assertions that `PartialOrd` and `Ord` implementations obey their laws
+//! for a given set of sample values:
+//!
+//! * [`assert_ord_laws`]
+//! * [`assert_partial_ord_laws`]
+//!
+//! Assertions that `PartialEq` implementations obey their laws,
+//! and that [`Hash`] implementations agree with them:
+//!
+//! * [`assert_eq_laws`]
+//! * [`assert_hash_consistent`]
+//!
+//! Chained comparisons with any of the `<`, `<=`, `>`, `>=`, `==` and `!=` operators:
+//!
+//! * [`assert_cmp`]
+//!
+//! Assertions that the values of an iterator are sorted:
+//!
+//! * [`assert_sorted`]
+//! * [`assert_sorted_by`]
+//! * [`assert_sorted_by_key`]
+//!
+//! Assertions that a sequence of values only goes in one direction,
+//! optionally by a limited step at a time:
+//!
+//! * [`assert_monotonic`]
+//!
+//! Assertions that a value is (or is not) contained in a [`RangeBounds`]:
+//!
+//! * [`assert_in_range`]
+//! * [`assert_not_in_range`]
+//!
+//! Assertions for floating point numbers and other inexact values,
+//! see the [`Approx`] trait for supported types:
+//!
+//! * [`assert_approx_eq`]
+//! * [`assert_near`]
+//!
 //! ### Matching
 //!
 //! * [`assert_matches`]
+//! * [`assert_not_matches`]
+//! * [`assert_let`]
+//!
+//! Assertions that all, or any, of the elements of an iterator match a pattern:
+//!
+//! * [`assert_all_match`]
+//! * [`assert_any_match`]
+//!
+//! Assertions on enum variants, ignoring their fields:
+//!
+//! * [`assert_variant`]
+//! * [`assert_same_variant`]
+//!
+//! Assertions on the fields of a struct, listing every field that does not meet its expectation:
+//!
+//! * [`assert_struct`]
+//!
+//! ### Formatting
+//!
+//! Assertions on the [`Display`] output of a value, which is searched as it is being
+//! formatted, without allocating:
+//!
+//! * [`assert_display_contains`]
 //!
 //! ### `Result` macros
 //!
@@ -54,7 +139,22 @@
 //! * [`assert_ok`]
 //! * [`assert_err`]
 //! * [`assert_ok_eq`]
+//! * [`assert_ok_ne`]
 //! * [`assert_err_eq`]
+//! * [`assert_err_ne`]
+//! * [`assert_ok_matches`]
+//! * [`assert_err_matches`]
+//! * [`assert_err_contains`]
+//! * [`assert_err_source_chain`] (requires the `std` feature)
+//! * [`assert_err_source_chain_exact`] (requires the `std` feature)
+//! * [`assert_err_downcast`] (requires the `std` feature)
+//!
+//! Assertions for the [`ErrorKind`] of [`io::Result`] errors, which require the `std` feature:
+//!
+//! * [`assert_io_err_kind`]
+//! * [`assert_would_block`]
+//! * [`assert_interrupted`]
+//! * [`assert_timed_out`]
 //!
 //! ### `Option` macros
 //!
@@ -63,6 +163,8 @@
 //! * [`assert_some`]
 //! * [`assert_none`]
 //! * [`assert_some_eq`]
+//! * [`assert_some_ne`]
+//! * [`assert_some_matches`]
 //!
 //! ### `Poll` macros
 //!
@@ -73,6 +175,8 @@
 //! * [`assert_ready_err`]
 //! * [`assert_ready_pending`]
 //! * [`assert_ready_eq`]
+//! * [`assert_ready_ne`]
+//! * [`assert_ready_matches`]
 //!
 //! [`core`]: https://doc.rust-lang.org/stable/core/#macros
 //! [`std`]: https://doc.rust-lang.org/stable/std/#macros
@@ -85,22 +189,125 @@
 //! [`assert_gt`]: ./macro.assert_gt.html
 //! [`assert_le`]: ./macro.assert_le.html
 //! [`assert_lt`]: ./macro.assert_lt.html
+//! [`assert_ge_strict`]: ./macro.assert_ge_strict.html
+//! [`assert_gt_strict`]: ./macro.assert_gt_strict.html
+//! [`assert_le_strict`]: ./macro.assert_le_strict.html
+//! [`assert_lt_strict`]: ./macro.assert_lt_strict.html
+//! [`assert_cmp`]: ./macro.assert_cmp.html
+//! [`Ordering`]: https://doc.rust-lang.org/core/cmp/enum.Ordering.html
+//! [`assert_ordering`]: ./macro.assert_ordering.html
+//! [`assert_cmp_eq`]: ./macro.assert_cmp_eq.html
+//! [`assert_partial_cmp_eq`]: ./macro.assert_partial_cmp_eq.html
+//! [`assert_ord_laws`]: ./macro.assert_ord_laws.html
+//! [`assert_partial_ord_laws`]: ./macro.assert_partial_ord_laws.html
+//! [`Hash`]: https://doc.rust-lang.org/core/hash/trait.Hash.html
+//! [`assert_eq_laws`]: ./macro.assert_eq_laws.html
+//! [`assert_hash_consistent`]: ./macro.assert_hash_consistent.html
+//! [`assert_sorted`]: ./macro.assert_sorted.html
+//! [`assert_sorted_by`]: ./macro.assert_sorted_by.html
+//! [`assert_sorted_by_key`]: ./macro.assert_sorted_by_key.html
+//! [`assert_monotonic`]: ./macro.assert_monotonic.html
+//! [`Delta`]: ./trait.Delta.html
+//! [`Debug`]: https://doc.rust-lang.org/core/fmt/trait.Debug.html
+//! [`RangeBounds`]: https://doc.rust-lang.org/core/ops/trait.RangeBounds.html
+//! [`assert_in_range`]: ./macro.assert_in_range.html
+//! [`assert_not_in_range`]: ./macro.assert_not_in_range.html
+//! [`assert_approx_eq`]: ./macro.assert_approx_eq.html
+//! [`assert_near`]: ./macro.assert_near.html
+//! [`Approx`]: ./trait.Approx.html
 //! [`assert_some`]: ./macro.assert_some.html
 //! [`assert_none`]: ./macro.assert_none.html
 //! [`assert_some_eq`]: ./macro.assert_some_eq.html
+//! [`assert_some_ne`]: ./macro.assert_some_ne.html
+//! [`assert_some_matches`]: ./macro.assert_some_matches.html
 //! [`assert_ok`]: ./macro.assert_ok.html
 //! [`assert_err`]: ./macro.assert_err.html
 //! [`assert_ok_eq`]: ./macro.assert_ok_eq.html
+//! [`assert_ok_ne`]: ./macro.assert_ok_ne.html
 //! [`assert_err_eq`]: ./macro.assert_err_eq.html
+//! [`assert_err_ne`]: ./macro.assert_err_ne.html
+//! [`assert_ok_matches`]: ./macro.assert_ok_matches.html
+//! [`assert_err_matches`]: ./macro.assert_err_matches.html
+//! [`assert_err_contains`]: ./macro.assert_err_contains.html
+//! [`assert_err_source_chain`]: ./macro.assert_err_source_chain.html
+//! [`assert_err_source_chain_exact`]: ./macro.assert_err_source_chain_exact.html
+//! [`assert_err_downcast`]: ./macro.assert_err_downcast.html
+//! [`ErrorKind`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html
+//! [`io::Result`]: https://doc.rust-lang.org/std/io/type.Result.html
+//! [`assert_io_err_kind`]: ./macro.assert_io_err_kind.html
+//! [`assert_would_block`]: ./macro.assert_would_block.html
+//! [`assert_interrupted`]: ./macro.assert_interrupted.html
+//! [`assert_timed_out`]: ./macro.assert_timed_out.html
+//! [`assert_display_contains`]: ./macro.assert_display_contains.html
+//! [`Display`]: https://doc.rust-lang.org/core/fmt/trait.Display.html
 //! [`assert_ready`]: ./macro.assert_ready.html
 //! [`assert_ready_ok`]: ./macro.assert_ready_ok.html
 //! [`assert_ready_err`]: ./macro.assert_ready_err.html
 //! [`assert_ready_pending`]: ./macro.assert_ready_pending.html
 //! [`assert_ready_eq`]: ./macro.assert_ready_eq.html
+//! [`assert_ready_ne`]: ./macro.assert_ready_ne.html
+//! [`assert_ready_matches`]: ./macro.assert_ready_matches.html
 //! [`assert_matches`]: ./macro.assert_matches.html
+//! [`assert_not_matches`]: ./macro.assert_not_matches.html
+//! [`assert_let`]: ./macro.assert_let.html
+//! [`assert_all_match`]: ./macro.assert_all_match.html
+//! [`assert_any_match`]: ./macro.assert_any_match.html
+//! [`assert_variant`]: ./macro.assert_variant.html
+//! [`assert_same_variant`]: ./macro.assert_same_variant.html
+//! [`assert_struct`]: ./macro.assert_struct.html
+
+#[cfg(all(feature = "std", rustc_1_30))]
+extern crate std;
+
+// `core` cannot be linked before Rust 1.6, where `std` re-exports everything used from it.
+#[cfg(not(rustc_1_6))]
+extern crate std as core;
+
+// Same as the `?` operator, which cannot be used here, as its expansion is marked with
+// `#[allow(unreachable_code)]`, an error under `#![forbid(unused)]` on older compilers.
+#[cfg(rustc_1_30)]
+macro_rules! tri {
+    ($expr:expr) => {
+        match $expr {
+            Ok(value) => value,
+            Err(error) => return Err(error),
+        }
+    };
+}
+
+// Rust 1.10 and older parse the files of all modules, including those that are configured out, and
+// reject syntax they do not know, like `dyn Trait`. Items using such syntax are passed through this
+// macro, so that these compilers only see them as tokens.
+#[cfg(rustc_1_30)]
+macro_rules! newer_syntax {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+// Without `delta`, the comparison macros do not report the difference between the operands.
+#[cfg(not(rustc_1_31))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __delta_message {
+    ($left:expr, $right:expr) => {
+        ""
+    };
+}
+
+// Without `debug`, the values in the failure messages are required to implement `Debug`.
+#[cfg(not(rustc_1_31))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_value {
+    ($value:expr) => {
+        &$value
+    };
+}
 
 mod assert_err;
 mod assert_err_eq;
+mod assert_err_ne;
 mod assert_ge;
 mod assert_gt;
 mod assert_le;
@@ -108,8 +315,10 @@ mod assert_lt;
 mod assert_none;
 mod assert_ok;
 mod assert_ok_eq;
+mod assert_ok_ne;
 mod assert_some;
 mod assert_some_eq;
+mod assert_some_ne;
 
 #[cfg(has_task_poll)]
 mod assert_pending;
@@ -119,8 +328,172 @@ mod assert_ready;
 mod assert_ready_eq;
 #[cfg(has_task_poll)]
 mod assert_ready_err;
+#[cfg(all(has_task_poll, rustc_1_26))]
+mod assert_ready_matches;
+#[cfg(has_task_poll)]
+mod assert_ready_ne;
 #[cfg(has_task_poll)]
 mod assert_ready_ok;
 
 #[cfg(rustc_1_26)]
 mod assert_matches;
+
+#[cfg(rustc_1_30)]
+mod assert_all_match;
+#[cfg(rustc_1_30)]
+mod assert_approx_eq;
+#[cfg(rustc_1_30)]
+mod assert_cmp;
+#[cfg(rustc_1_30)]
+mod assert_cmp_eq;
+#[cfg(rustc_1_30)]
+mod assert_display_contains;
+#[cfg(rustc_1_30)]
+mod assert_err_contains;
+#[cfg(rustc_1_30)]
+mod assert_err_matches;
+#[cfg(rustc_1_30)]
+mod assert_ge_strict;
+#[cfg(rustc_1_30)]
+mod assert_gt_strict;
+#[cfg(rustc_1_30)]
+mod assert_le_strict;
+#[cfg(rustc_1_30)]
+mod assert_lt_strict;
+#[cfg(rustc_1_30)]
+mod assert_monotonic;
+#[cfg(rustc_1_30)]
+mod assert_near;
+#[cfg(rustc_1_30)]
+mod assert_not_matches;
+#[cfg(rustc_1_30)]
+mod assert_ok_matches;
+#[cfg(rustc_1_30)]
+mod assert_partial_cmp_eq;
+#[cfg(rustc_1_30)]
+mod assert_same_variant;
+#[cfg(rustc_1_30)]
+mod assert_some_matches;
+#[cfg(rustc_1_30)]
+mod assert_sorted;
+#[cfg(rustc_1_30)]
+mod assert_sorted_by;
+#[cfg(rustc_1_30)]
+mod assert_sorted_by_key;
+#[cfg(rustc_1_30)]
+mod assert_struct;
+#[cfg(rustc_1_30)]
+mod assert_variant;
+#[cfg(rustc_1_30)]
+mod contains;
+#[cfg(rustc_1_30)]
+mod sub_pattern;
+
+#[cfg(rustc_1_30)]
+newer_syntax! {
+    mod approx;
+    mod fields;
+    mod monotonic;
+    mod sorted;
+}
+
+#[cfg(rustc_1_31)]
+mod assert_any_match;
+#[cfg(rustc_1_31)]
+mod assert_eq_laws;
+#[cfg(rustc_1_31)]
+mod assert_hash_consistent;
+#[cfg(rustc_1_31)]
+mod assert_ord_laws;
+#[cfg(rustc_1_31)]
+mod assert_ordering;
+#[cfg(rustc_1_31)]
+mod assert_partial_ord_laws;
+#[cfg(rustc_1_31)]
+mod debug;
+#[cfg(rustc_1_31)]
+mod delta;
+#[cfg(rustc_1_31)]
+mod elements;
+#[cfg(rustc_1_31)]
+mod ordering;
+
+#[cfg(rustc_1_31)]
+newer_syntax! {
+    mod laws;
+}
+
+#[cfg(rustc_1_35)]
+mod assert_in_range;
+#[cfg(rustc_1_35)]
+mod assert_not_in_range;
+
+#[cfg(rustc_1_65)]
+mod assert_let;
+
+#[cfg(all(feature = "std", rustc_1_30))]
+mod assert_err_downcast;
+#[cfg(all(feature = "std", rustc_1_37))]
+mod assert_err_source_chain;
+#[cfg(all(feature = "std", rustc_1_37))]
+mod assert_err_source_chain_exact;
+#[cfg(all(feature = "std", rustc_1_30))]
+mod assert_interrupted;
+#[cfg(all(feature = "std", rustc_1_30))]
+mod assert_io_err_kind;
+#[cfg(all(feature = "std", rustc_1_30))]
+mod assert_timed_out;
+#[cfg(all(feature = "std", rustc_1_30))]
+mod assert_would_block;
+#[cfg(all(feature = "std", rustc_1_30))]
+mod display;
+
+#[cfg(all(feature = "std", rustc_1_37))]
+newer_syntax! {
+    mod source_chain;
+}
+
+#[cfg(rustc_1_30)]
+pub use self::approx::{Approx, ApproxError, Tolerance};
+#[cfg(rustc_1_31)]
+pub use self::delta::{Delta, IntegerDifference};
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(rustc_1_30)]
+    pub use super::contains::display_contains;
+    #[cfg(rustc_1_31)]
+    pub use super::debug::{DebugValue, MaybeDebug, NoDebug};
+    #[cfg(rustc_1_31)]
+    pub use super::delta::{DeltaMessage, DeltaOperands};
+    #[cfg(all(feature = "std", rustc_1_30))]
+    pub use super::display::{DisplayLine, DisplayValue, MaybeDisplay};
+    #[cfg(rustc_1_31)]
+    pub use super::elements::Elements;
+    #[cfg(rustc_1_30)]
+    pub use super::fields::{Expected, FieldMismatch, FieldMismatches};
+    #[cfg(rustc_1_31)]
+    pub use super::laws::{
+        check_hash_consistent, check_ord_laws, check_partial_ord_laws, CheckEqLaws, EqSamples,
+        LawViolation,
+    };
+    #[cfg(rustc_1_30)]
+    pub use super::monotonic::{check_monotonic, check_monotonic_step, Direction, NotMonotonic};
+    #[cfg(rustc_1_31)]
+    pub use super::ordering::{ActualOrdering, OrderingOperands};
+    #[cfg(rustc_1_30)]
+    pub use super::sorted::{check_sorted, check_sorted_by, check_sorted_by_key, Order, Unsorted};
+    #[cfg(all(feature = "std", rustc_1_37))]
+    pub use super::source_chain::{check_source_chain, Cause, ChainMismatch, ChainMode};
+    #[cfg(rustc_1_30)]
+    pub use super::sub_pattern::SubPattern;
+    #[cfg(rustc_1_30)]
+    pub use core::cell::Cell;
+    pub use core::cmp::{Ord, Ordering, PartialOrd};
+    #[cfg(rustc_1_31)]
+    pub use core::fmt::Debug;
+    #[cfg(rustc_1_30)]
+    pub use core::mem::discriminant;
+    #[cfg(all(feature = "std", rustc_1_30))]
+    pub use std::io::ErrorKind;
+}
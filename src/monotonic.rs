@@ -0,0 +1,161 @@
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Sub;
+
+/// Direction checked by `assert_monotonic!`.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    StrictlyIncreasing,
+    Decreasing,
+    StrictlyDecreasing,
+}
+
+impl Direction {
+    fn holds<T: PartialOrd + ?Sized>(self, left: &T, right: &T) -> bool {
+        match self {
+            Direction::Increasing => left <= right,
+            Direction::StrictlyIncreasing => left < right,
+            Direction::Decreasing => left >= right,
+            Direction::StrictlyDecreasing => left > right,
+        }
+    }
+
+    // Only called once the direction holds, so that the step is never negative.
+    fn step<T: Clone + Sub<Output = T>>(self, left: &T, right: &T) -> T {
+        match self {
+            Direction::Increasing | Direction::StrictlyIncreasing => right.clone() - left.clone(),
+            Direction::Decreasing | Direction::StrictlyDecreasing => left.clone() - right.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            Direction::Increasing => "increasing",
+            Direction::StrictlyIncreasing => "strictly increasing",
+            Direction::Decreasing => "decreasing",
+            Direction::StrictlyDecreasing => "strictly decreasing",
+        })
+    }
+}
+
+/// First pair of consecutive values found by `assert_monotonic!` to break the sequence,
+/// either by going in the wrong direction or by taking too large a step.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct NotMonotonic<T, S> {
+    direction: Direction,
+    index: usize,
+    left: T,
+    right: T,
+    step: Option<(S, S)>,
+}
+
+impl<T: fmt::Debug, S: fmt::Debug> fmt::Display for NotMonotonic<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.step {
+            Some(_) => tri!(write!(
+                f,
+                "step between values at indices `{}` and `{}` exceeds `max_step`",
+                self.index - 1,
+                self.index
+            )),
+            None => tri!(write!(
+                f,
+                "values at indices `{}` and `{}` are not {}",
+                self.index - 1,
+                self.index,
+                self.direction
+            )),
+        }
+        tri!(write!(
+            f,
+            "\n    [{}]: `{:?}`,\n    [{}]: `{:?}`",
+            self.index - 1,
+            self.left,
+            self.index,
+            self.right
+        ));
+        if let Some((ref step, ref max_step)) = self.step {
+            tri!(write!(
+                f,
+                ",\n    step: `{:?}`,\n    max_step: `{:?}`",
+                step, max_step
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn check_pairs<I, S, F>(values: I, mut check: F) -> Result<(), NotMonotonic<I::Item, S>>
+where
+    I: IntoIterator,
+    F: FnMut(&I::Item, &I::Item) -> Result<(), (Direction, Option<(S, S)>)>,
+{
+    let mut values = values.into_iter();
+    let mut left = match values.next() {
+        Some(first) => first,
+        None => return Ok(()),
+    };
+    for (index, right) in values.enumerate() {
+        if let Err((direction, step)) = check(&left, &right) {
+            return Err(NotMonotonic {
+                direction,
+                index: index + 1,
+                left,
+                right,
+                step,
+            });
+        }
+        left = right;
+    }
+    Ok(())
+}
+
+/// Checks that values go in the given direction for `assert_monotonic!`.
+#[doc(hidden)]
+pub fn check_monotonic<I>(values: I, direction: Direction) -> Result<(), NotMonotonic<I::Item, ()>>
+where
+    I: IntoIterator,
+    I::Item: PartialOrd,
+{
+    check_pairs(values, |left, right| {
+        if direction.holds(left, right) {
+            Ok(())
+        } else {
+            Err((direction, None))
+        }
+    })
+}
+
+/// Checks that values go in the given direction by at most `max_step` at a time
+/// for `assert_monotonic!`.
+///
+/// Values are borrowed as the type of `max_step`, so that both iterators of values
+/// and iterators of references to them can be checked.
+#[doc(hidden)]
+pub fn check_monotonic_step<I, T>(
+    values: I,
+    direction: Direction,
+    max_step: T,
+) -> Result<(), NotMonotonic<I::Item, T>>
+where
+    I: IntoIterator,
+    I::Item: Borrow<T>,
+    T: PartialOrd + Clone + Sub<Output = T>,
+{
+    check_pairs(values, |left, right| {
+        let (left, right) = (left.borrow(), right.borrow());
+        if !direction.holds(left, right) {
+            return Err((direction, None));
+        }
+        let step = direction.step(left, right);
+        if step > max_step {
+            return Err((direction, Some((step, max_step.clone()))));
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,28 @@
+use core::cmp::Ordering;
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct OrderingOperands<'a, L: ?Sized, R: ?Sized>(pub &'a L, pub &'a R);
+
+/// Computes the results of `partial_cmp` and, when available, `cmp` for `assert_ordering!`.
+///
+/// Operands of the same [`Ord`] type resolve to the impl on `OrderingOperands` itself, everything
+/// else falls back to the impl on `&OrderingOperands` through autoref, which only calls `partial_cmp`.
+#[doc(hidden)]
+pub trait ActualOrdering {
+    fn actual_ordering(&self) -> (Option<Ordering>, Option<Ordering>);
+}
+
+impl<'a, T: Ord + ?Sized> ActualOrdering for OrderingOperands<'a, T, T> {
+    fn actual_ordering(&self) -> (Option<Ordering>, Option<Ordering>) {
+        (self.0.partial_cmp(self.1), Some(self.0.cmp(self.1)))
+    }
+}
+
+impl<'a, 'b, L: PartialOrd<R> + ?Sized, R: ?Sized> ActualOrdering
+    for &'b OrderingOperands<'a, L, R>
+{
+    fn actual_ordering(&self) -> (Option<Ordering>, Option<Ordering>) {
+        (self.0.partial_cmp(self.1), None)
+    }
+}
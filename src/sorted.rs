@@ -0,0 +1,145 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::mem;
+
+/// Order checked by the `assert_sorted!` family of macros.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    Ascending,
+    StrictlyAscending,
+    Descending,
+    StrictlyDescending,
+}
+
+impl Order {
+    fn holds(self, ordering: Option<Ordering>) -> bool {
+        let ordering = match ordering {
+            Some(ordering) => ordering,
+            None => return false,
+        };
+        match self {
+            Order::Ascending => ordering != Ordering::Greater,
+            Order::StrictlyAscending => ordering == Ordering::Less,
+            Order::Descending => ordering != Ordering::Less,
+            Order::StrictlyDescending => ordering == Ordering::Greater,
+        }
+    }
+}
+
+impl fmt::Display for Order {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            Order::Ascending => "ascending",
+            Order::StrictlyAscending => "strictly ascending",
+            Order::Descending => "descending",
+            Order::StrictlyDescending => "strictly descending",
+        })
+    }
+}
+
+/// First element found out of order by the `assert_sorted!` family of macros.
+///
+/// Holds the element at `index` and the one before it, which are not in order,
+/// along with their neighbours, if any.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct Unsorted<T> {
+    order: Order,
+    index: usize,
+    before: Option<T>,
+    left: T,
+    right: T,
+    after: Option<T>,
+}
+
+impl<T: fmt::Debug> fmt::Display for Unsorted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        tri!(write!(
+            f,
+            "element at index `{}` is not in {} order",
+            self.index, self.order
+        ));
+        if let Some(ref before) = self.before {
+            tri!(write!(f, "\n    [{}]: `{:?}`,", self.index - 2, before));
+        }
+        tri!(write!(f, "\n    [{}]: `{:?}`,", self.index - 1, self.left));
+        tri!(write!(f, "\n    [{}]: `{:?}`", self.index, self.right));
+        if let Some(ref after) = self.after {
+            tri!(write!(f, ",\n    [{}]: `{:?}`", self.index + 1, after));
+        }
+        Ok(())
+    }
+}
+
+// Keeps a window of the last two elements only, so that any iterator can be checked without
+// allocating or requiring `Clone`, and pulls one more element for the message on failure.
+fn check_order<I, F>(values: I, order: Order, mut compare: F) -> Result<(), Unsorted<I::Item>>
+where
+    I: IntoIterator,
+    F: FnMut(&I::Item, &I::Item) -> Option<Ordering>,
+{
+    let mut values = values.into_iter();
+    let mut left = match values.next() {
+        Some(first) => first,
+        None => return Ok(()),
+    };
+    let mut before = None;
+    let mut index = 1;
+    while let Some(right) = values.next() {
+        if !order.holds(compare(&left, &right)) {
+            return Err(Unsorted {
+                order,
+                index,
+                before,
+                left,
+                right,
+                after: values.next(),
+            });
+        }
+        before = Some(mem::replace(&mut left, right));
+        index += 1;
+    }
+    Ok(())
+}
+
+/// Checks that values are in the given order for `assert_sorted!`.
+#[doc(hidden)]
+pub fn check_sorted<I>(values: I, order: Order) -> Result<(), Unsorted<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: PartialOrd,
+{
+    check_order(values, order, PartialOrd::partial_cmp)
+}
+
+/// Checks that values are in the given order by a comparator for `assert_sorted_by!`.
+#[doc(hidden)]
+pub fn check_sorted_by<I, F>(
+    values: I,
+    order: Order,
+    mut compare: F,
+) -> Result<(), Unsorted<I::Item>>
+where
+    I: IntoIterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    check_order(values, order, |left, right| Some(compare(left, right)))
+}
+
+/// Checks that the keys of values are in the given order for `assert_sorted_by_key!`.
+#[doc(hidden)]
+pub fn check_sorted_by_key<I, F, K>(
+    values: I,
+    order: Order,
+    mut key: F,
+) -> Result<(), Unsorted<I::Item>>
+where
+    I: IntoIterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialOrd,
+{
+    check_order(values, order, |left, right| {
+        key(left).partial_cmp(&key(right))
+    })
+}
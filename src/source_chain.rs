@@ -0,0 +1,142 @@
+use std::error::Error;
+use std::fmt;
+use std::iter;
+use std::string::ToString;
+
+/// Cause expected in the source chain of an error by `assert_err_source_chain!`.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub enum Cause {
+    /// Cause of the named type, checked by the given function.
+    Type(&'static str, fn(&(dyn Error + 'static)) -> bool),
+    /// Cause whose `Display` output is exactly the given message.
+    Message(&'static str),
+}
+
+impl Cause {
+    /// Expects a cause of type `T`, named `name` in the failure messages.
+    pub fn of<T: Error + 'static>(name: &'static str) -> Self {
+        fn is<T: Error + 'static>(error: &(dyn Error + 'static)) -> bool {
+            error.is::<T>()
+        }
+        Cause::Type(name, is::<T>)
+    }
+
+    fn matches(&self, error: &(dyn Error + 'static)) -> bool {
+        match *self {
+            Cause::Type(_, is) => is(error),
+            Cause::Message(message) => error.to_string() == message,
+        }
+    }
+}
+
+impl fmt::Debug for Cause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Cause::Type(name, _) => f.write_str(name),
+            Cause::Message(message) => write!(f, "{:?}", message),
+        }
+    }
+}
+
+/// How the source chain is compared against the expected causes.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainMode {
+    /// The expected causes appear in the chain in order, possibly with other causes between them.
+    Contains,
+    /// The chain consists of exactly the expected causes, in order.
+    Exact,
+}
+
+#[derive(Debug)]
+enum Mismatch {
+    Missing(usize),
+    Differs(usize),
+    Length(usize),
+}
+
+/// Source chain of an error that does not match the causes expected by
+/// `assert_err_source_chain!`.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct ChainMismatch<'a> {
+    error: &'a (dyn Error + 'static),
+    expected: &'a [Cause],
+    mismatch: Mismatch,
+}
+
+impl<'a> fmt::Display for ChainMismatch<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mismatch {
+            Mismatch::Missing(_) => {
+                tri!(f
+                    .write_str("error source chain does not contain the expected causes in order."))
+            }
+            Mismatch::Differs(_) | Mismatch::Length(_) => {
+                tri!(f.write_str("error source chain does not equal the expected causes."))
+            }
+        }
+        tri!(f.write_str("\n    chain:"));
+        for (index, cause) in causes(self.error).enumerate() {
+            tri!(write!(f, "\n        [{}]: `{}`", index, cause));
+        }
+        tri!(write!(f, "\n    expected: {:?}", self.expected));
+        match self.mismatch {
+            Mismatch::Missing(index) => write!(f, "\n    missing: {:?}", self.expected[index]),
+            Mismatch::Differs(index) => write!(
+                f,
+                "\n    mismatch: [{}] is not {:?}",
+                index, self.expected[index]
+            ),
+            Mismatch::Length(length) => write!(
+                f,
+                "\n    mismatch: expected {} causes, got {}",
+                self.expected.len(),
+                length
+            ),
+        }
+    }
+}
+
+/// The error itself, followed by its sources.
+fn causes<'a>(error: &'a (dyn Error + 'static)) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+    iter::successors(Some(error), |&cause| cause.source())
+}
+
+/// Checks the source chain of `error`, starting with the error itself, against `expected`.
+#[doc(hidden)]
+pub fn check_source_chain<'a>(
+    error: &'a (dyn Error + 'static),
+    mode: ChainMode,
+    expected: &'a [Cause],
+) -> Result<(), ChainMismatch<'a>> {
+    let mismatch = match mode {
+        ChainMode::Contains => {
+            let mut chain = causes(error);
+            expected
+                .iter()
+                .position(|cause| !chain.any(|actual| cause.matches(actual)))
+                .map(Mismatch::Missing)
+        }
+        ChainMode::Exact => {
+            let length = causes(error).count();
+            match causes(error)
+                .zip(expected)
+                .position(|(actual, cause)| !cause.matches(actual))
+            {
+                Some(index) => Some(Mismatch::Differs(index)),
+                None if length != expected.len() => Some(Mismatch::Length(length)),
+                None => None,
+            }
+        }
+    };
+    match mismatch {
+        Some(mismatch) => Err(ChainMismatch {
+            error,
+            expected,
+            mismatch,
+        }),
+        None => Ok(()),
+    }
+}
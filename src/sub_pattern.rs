@@ -0,0 +1,46 @@
+/// Result of checking a sub-pattern of `assert_matches!` against the value at its path.
+///
+/// The paths and sub-patterns are stringified by the macro, e.g. `.0.status` and `200`.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub enum SubPattern {
+    /// The sub-pattern and everything inside of it matches.
+    Matched,
+    /// The sub-pattern at the path does not match.
+    Mismatched(&'static str, &'static str),
+    /// The sub-pattern at the path cannot be checked on its own, e.g. as it might be a binding.
+    Unchecked(&'static str, &'static str),
+    /// More than one of the sub-patterns cannot be checked on their own.
+    Ambiguous,
+}
+
+impl SubPattern {
+    /// Combines the results of two sibling sub-patterns, keeping the first mismatch.
+    pub fn and(self, other: SubPattern) -> SubPattern {
+        match self {
+            SubPattern::Mismatched(..) => self,
+            SubPattern::Matched => other,
+            SubPattern::Unchecked(..) | SubPattern::Ambiguous => match other {
+                SubPattern::Mismatched(..) => other,
+                SubPattern::Matched => self,
+                SubPattern::Unchecked(..) | SubPattern::Ambiguous => SubPattern::Ambiguous,
+            },
+        }
+    }
+
+    /// Returns the path and the innermost sub-pattern of a pattern that does not match.
+    ///
+    /// A single unchecked sub-pattern is the one that does not match, as everything
+    /// else does. Nothing is returned for the pattern itself, which has an empty path.
+    pub fn mismatch(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            SubPattern::Mismatched(path, sub_pattern)
+            | SubPattern::Unchecked(path, sub_pattern)
+                if !path.is_empty() =>
+            {
+                Some((path, sub_pattern))
+            }
+            _ => None,
+        }
+    }
+}